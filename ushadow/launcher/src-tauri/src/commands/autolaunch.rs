@@ -0,0 +1,33 @@
+use auto_launch::AutoLaunch;
+
+/// Build an `AutoLaunch` handle for the current executable.
+/// Tray icon args are empty — reopening via login should land on the normal
+/// tray-hidden startup, same as a manual launch.
+fn auto_launch() -> Result<AutoLaunch, String> {
+    let exe_path = std::env::current_exe()
+        .map_err(|e| format!("Failed to resolve launcher executable: {}", e))?;
+    let exe_path = exe_path.to_str().ok_or("Launcher executable path is not valid UTF-8")?;
+
+    Ok(AutoLaunch::new("Ushadow Launcher", exe_path, &[] as &[&str]))
+}
+
+/// Whether the launcher is currently registered to start at login.
+#[tauri::command]
+pub fn get_autolaunch_enabled() -> Result<bool, String> {
+    auto_launch()?
+        .is_enabled()
+        .map_err(|e| format!("Failed to query auto-launch state: {}", e))
+}
+
+/// Register or unregister the launcher with the OS login manager
+/// (macOS LaunchAgents, Windows registry Run key, Linux autostart .desktop).
+#[tauri::command]
+pub fn set_autolaunch(enabled: bool) -> Result<(), String> {
+    let launch = auto_launch()?;
+
+    if enabled {
+        launch.enable().map_err(|e| format!("Failed to enable auto-launch: {}", e))
+    } else {
+        launch.disable().map_err(|e| format!("Failed to disable auto-launch: {}", e))
+    }
+}
@@ -0,0 +1,104 @@
+use std::collections::HashMap;
+use std::io;
+use std::process::ExitStatus;
+use super::utils::shell_command;
+
+/// Abstracts "run a command and read its exit status/stdout/stderr" so the
+/// prerequisite checks can be exercised with canned output in tests instead
+/// of depending on whatever happens to be installed on the test machine.
+pub trait CommandRunner: Send + Sync {
+    /// `args` is the full argv, e.g. `["docker", "--version"]`.
+    fn run(&self, args: &[&str]) -> io::Result<(ExitStatus, String, String)>;
+}
+
+/// The real runner used outside of tests: joins `args` back into a command
+/// line and runs it through a login shell, same as the old direct
+/// `shell_command` calls, so PATH/profile sourcing behavior is unchanged.
+pub struct ShellCommandRunner;
+
+impl CommandRunner for ShellCommandRunner {
+    fn run(&self, args: &[&str]) -> io::Result<(ExitStatus, String, String)> {
+        let command_line = args.join(" ");
+        let output = shell_command(&command_line).output()?;
+        Ok((
+            output.status,
+            String::from_utf8_lossy(&output.stdout).to_string(),
+            String::from_utf8_lossy(&output.stderr).to_string(),
+        ))
+    }
+}
+
+/// A canned response for one `MockCommandRunner` entry.
+#[derive(Clone)]
+pub struct MockResponse {
+    pub success: bool,
+    pub stdout: String,
+    pub stderr: String,
+}
+
+impl MockResponse {
+    pub fn ok(stdout: &str) -> Self {
+        Self { success: true, stdout: stdout.to_string(), stderr: String::new() }
+    }
+
+    pub fn fail(stderr: &str) -> Self {
+        Self { success: false, stdout: String::new(), stderr: stderr.to_string() }
+    }
+}
+
+/// Maps an exact argv (joined with spaces, e.g. `"docker --version"`) to a
+/// canned response. Unmapped commands fail with exit code 127 ("not found"),
+/// matching what a missing binary looks like to the real runner.
+#[derive(Default, Clone)]
+pub struct MockCommandRunner {
+    responses: HashMap<String, MockResponse>,
+}
+
+impl MockCommandRunner {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with(mut self, args: &[&str], response: MockResponse) -> Self {
+        self.responses.insert(args.join(" "), response);
+        self
+    }
+}
+
+#[cfg(unix)]
+fn exit_status(code: i32) -> ExitStatus {
+    use std::os::unix::process::ExitStatusExt;
+    ExitStatus::from_raw(code << 8)
+}
+
+#[cfg(windows)]
+fn exit_status(code: i32) -> ExitStatus {
+    use std::os::windows::process::ExitStatusExt;
+    ExitStatus::from_raw(code as u32)
+}
+
+impl CommandRunner for MockCommandRunner {
+    fn run(&self, args: &[&str]) -> io::Result<(ExitStatus, String, String)> {
+        match self.responses.get(&args.join(" ")) {
+            Some(response) => Ok((
+                exit_status(if response.success { 0 } else { 1 }),
+                response.stdout.clone(),
+                response.stderr.clone(),
+            )),
+            None => Ok((exit_status(127), String::new(), "command not found".to_string())),
+        }
+    }
+}
+
+/// Process-wide env vars (`MOCK_MODE`, `MOCK_*`, `USHADOW_*_BINARY`, ...) are
+/// read by `prerequisites.rs`/`preflight.rs` outside of mock mode too, so any
+/// test across either file that sets one has to hold this lock for as long
+/// as the var is set; otherwise the default multi-threaded test runner can
+/// interleave two such tests and each sees the other's env var.
+#[cfg(test)]
+pub(crate) fn env_test_lock() -> std::sync::MutexGuard<'static, ()> {
+    static LOCK: std::sync::OnceLock<std::sync::Mutex<()>> = std::sync::OnceLock::new();
+    LOCK.get_or_init(|| std::sync::Mutex::new(()))
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
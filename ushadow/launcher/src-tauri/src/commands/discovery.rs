@@ -1,22 +1,132 @@
 use std::collections::HashSet;
+use std::collections::HashMap;
 use std::process::Command;
+use std::time::Duration;
+use serde::{Deserialize, Serialize};
 use crate::models::{DiscoveryResult, InfraService, UshadowEnvironment};
 use super::prerequisites::{check_docker, check_tailscale};
+use super::command_runner::ShellCommandRunner;
+use super::registry::{get_service_registry, MatchRule, ServiceDefinition, ServiceRegistry, WebuiPortRule};
+use super::utils::silent_command;
+
+/// One line of `docker ps --format '{{json .}}'` output.
+/// Only these fields are read; `Labels` and `State` default to empty when a
+/// Docker version omits them. Networks and other details are not read here.
+#[derive(Debug, Deserialize)]
+struct DockerPsEntry {
+    #[serde(rename = "Names")]
+    names: String,
+    #[serde(rename = "Status")]
+    status: String,
+    #[serde(rename = "Ports")]
+    ports: String,
+    #[serde(rename = "Labels", default)]
+    labels: String,
+    #[serde(rename = "State", default)]
+    state: String,
+}
+
+impl DockerPsEntry {
+    fn is_running(&self) -> bool {
+        self.state == "running" || self.status.contains("Up")
+    }
+
+    fn ports(&self) -> Option<String> {
+        if self.ports.trim().is_empty() {
+            None
+        } else {
+            Some(self.ports.clone())
+        }
+    }
+
+    /// Parse the flat `Labels` string ("a=1,b=2") into a map, mirroring
+    /// what `docker inspect` would give us as structured JSON.
+    fn label_map(&self) -> HashMap<String, String> {
+        self.labels
+            .split(',')
+            .filter_map(|kv| kv.split_once('='))
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect()
+    }
+}
 
-/// Infrastructure service patterns
-const INFRA_PATTERNS: &[(&str, &str)] = &[
-    ("mongo", "MongoDB"),
-    ("redis", "Redis"),
-    ("neo4j", "Neo4j"),
-    ("qdrant", "Qdrant"),
-];
+#[derive(Debug, Deserialize)]
+struct LeaderInfo {
+    ushadow_api_url: String,
+}
 
-/// Discover running Ushadow environments and infrastructure
+/// Targets a single Docker daemon: either the local one, or a remote one
+/// reached via `DOCKER_HOST` (`ssh://user@host` or `tcp://host:2375`, commonly
+/// a Tailscale MagicDNS name).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DockerContext {
+    /// Human-readable label used to tag discovered environments, e.g. "local" or a hostname.
+    pub host: String,
+    /// `DOCKER_HOST` value to export for this context; `None` means the local daemon.
+    pub docker_host: Option<String>,
+}
+
+impl DockerContext {
+    pub fn local() -> Self {
+        Self { host: "local".to_string(), docker_host: None }
+    }
+
+    fn is_remote(&self) -> bool {
+        self.docker_host.is_some()
+    }
+
+    /// Build a `docker` invocation targeting this context.
+    fn docker_command(&self) -> Command {
+        let mut cmd = silent_command("docker");
+        if let Some(ref docker_host) = self.docker_host {
+            cmd.env("DOCKER_HOST", docker_host);
+        }
+        cmd
+    }
+}
+
+/// Discover running Ushadow environments and infrastructure on the local Docker daemon.
 #[tauri::command]
 pub async fn discover_environments() -> Result<DiscoveryResult, String> {
+    discover_for_context(&DockerContext::local()).await
+}
+
+/// Discover across several Docker contexts (local and/or remote hosts) and merge
+/// the results into a single `DiscoveryResult`, tagging each environment with
+/// the host it was found on.
+#[tauri::command]
+pub async fn discover_environments_multi(contexts: Vec<DockerContext>) -> Result<DiscoveryResult, String> {
+    let mut merged = DiscoveryResult {
+        infrastructure: vec![],
+        environments: vec![],
+        docker_ok: false,
+        tailscale_ok: false,
+    };
+
+    for ctx in &contexts {
+        match discover_for_context(ctx).await {
+            Ok(result) => {
+                merged.docker_ok = merged.docker_ok || result.docker_ok;
+                merged.tailscale_ok = merged.tailscale_ok || result.tailscale_ok;
+                merged.infrastructure.extend(result.infrastructure);
+                merged.environments.extend(result.environments);
+            }
+            Err(e) => {
+                // One unreachable remote host shouldn't hide environments on the others.
+                eprintln!("discovery failed for host '{}': {}", ctx.host, e);
+            }
+        }
+    }
+
+    Ok(merged)
+}
+
+/// Discover running Ushadow environments and infrastructure against a single Docker context.
+async fn discover_for_context(ctx: &DockerContext) -> Result<DiscoveryResult, String> {
     // Check prerequisites
-    let (docker_installed, docker_running, _) = check_docker();
-    let (tailscale_installed, tailscale_connected, _) = check_tailscale();
+    let runner = ShellCommandRunner;
+    let (docker_installed, docker_running, _) = check_docker(&runner);
+    let (tailscale_installed, tailscale_connected, _) = check_tailscale(&runner);
 
     let docker_ok = docker_installed && docker_running;
     let tailscale_ok = tailscale_installed && tailscale_connected;
@@ -30,11 +140,13 @@ pub async fn discover_environments() -> Result<DiscoveryResult, String> {
         });
     }
 
-    // Get all Docker containers
-    let output = Command::new("docker")
-        .args(["ps", "--format", "{{.Names}}|{{.Status}}|{{.Ports}}"])
+    // Get all Docker containers as newline-delimited JSON objects. This is far
+    // more robust than the old pipe-delimited `{{.Names}}|{{.Status}}|{{.Ports}}`
+    // format, which broke on container names or ports containing `|`.
+    let output = ctx.docker_command()
+        .args(["ps", "--format", "{{json .}}"])
         .output()
-        .map_err(|e| format!("Failed to get containers: {}", e))?;
+        .map_err(|e| format!("Failed to get containers on '{}': {}", ctx.host, e))?;
 
     if !output.status.success() {
         let stderr = String::from_utf8_lossy(&output.stderr);
@@ -43,78 +155,75 @@ pub async fn discover_environments() -> Result<DiscoveryResult, String> {
 
     let stdout = String::from_utf8_lossy(&output.stdout);
     let mut infrastructure = Vec::new();
-    let mut env_backends: Vec<(String, u16)> = Vec::new();
+    let mut env_backends: Vec<(String, u16, Option<u16>)> = Vec::new();
     let mut found_infra: HashSet<String> = HashSet::new();
+    let registry = get_service_registry()?;
 
-    // Parse Docker ps output
+    // Parse Docker ps output, one JSON object per line
     for line in stdout.lines() {
         let line = line.trim();
         if line.is_empty() {
             continue;
         }
 
-        let parts: Vec<&str> = line.split('|').collect();
-        if parts.len() < 2 {
-            continue;
-        }
+        let entry: DockerPsEntry = match serde_json::from_str(line) {
+            Ok(entry) => entry,
+            Err(_) => continue, // tolerate older Docker versions emitting non-JSON lines
+        };
 
-        let name = parts[0].trim();
-        let status = parts[1].trim();
-        let ports = if parts.len() > 2 { Some(parts[2].trim().to_string()) } else { None };
-        let is_running = status.contains("Up");
-
-        // Check infrastructure services
-        for (pattern, display_name) in INFRA_PATTERNS {
-            if name == *pattern || name.ends_with(&format!("-{}", pattern)) || name.ends_with(&format!("-{}-1", pattern)) {
-                if !found_infra.contains(*pattern) {
-                    found_infra.insert(pattern.to_string());
-                    infrastructure.push(InfraService {
-                        name: pattern.to_string(),
-                        display_name: display_name.to_string(),
-                        running: is_running,
-                        ports: ports.clone(),
-                    });
-                }
-            }
-        }
+        let name = entry.names.as_str();
+        let is_running = entry.is_running();
+        let ports = entry.ports();
+        let labels = entry.label_map();
 
-        // Check Ushadow environment backends
-        if name.contains("backend") && name.starts_with("ushadow") && !name.contains("chronicle") {
-            let env_name = if name == "ushadow-backend" {
-                "default".to_string()
-            } else {
-                name.trim_start_matches("ushadow-")
-                    .trim_end_matches("-backend")
-                    .to_string()
-            };
+        let service = match matching_service(&registry, name, &labels) {
+            Some(service) => service,
+            None => continue,
+        };
 
+        if service.is_backend {
             if let Some(ref port_str) = ports {
                 if let Some(port) = extract_port(port_str) {
                     if is_running {
-                        env_backends.push((env_name, port));
+                        let env_name = derive_env_name(service, name);
+                        let webui_port = derive_webui_port(service, port);
+                        env_backends.push((env_name, port, webui_port));
                     }
                 }
             }
+        } else if !found_infra.contains(&service.name) {
+            found_infra.insert(service.name.clone());
+            infrastructure.push(InfraService {
+                name: service.name.clone(),
+                display_name: service.display_name.clone(),
+                running: is_running,
+                ports: ports.clone(),
+            });
         }
     }
 
     // Build environment list with Tailscale URLs
     let mut environments = Vec::new();
-    for (env_name, backend_port) in env_backends {
+    for (env_name, backend_port, webui_port) in env_backends {
         let color = env_name.clone();
-        let tailscale_url = get_tailscale_url(backend_port);
+        let tailscale_url = get_tailscale_url(ctx, backend_port).await;
         let tailscale_active = tailscale_url.is_some();
 
-        let webui_port = if backend_port >= 8000 {
-            Some(backend_port - 5000)
-        } else {
-            None
-        };
-
-        let localhost_url = if let Some(wp) = webui_port {
-            format!("http://localhost:{}", wp)
-        } else {
-            format!("http://localhost:{}", backend_port)
+        // On a remote context, "localhost" means the remote machine, which isn't
+        // reachable from here. Prefer the Tailscale URL; failing that, fall back
+        // to an SSH-forwarded localhost URL the user can reach via `ssh -L`.
+        let localhost_url = match (ctx.is_remote(), &tailscale_url) {
+            (true, Some(url)) => url.clone(),
+            (true, None) => format!("http://localhost:{}  (forward via: ssh -L {}:localhost:{} {})",
+                webui_port.unwrap_or(backend_port), webui_port.unwrap_or(backend_port),
+                webui_port.unwrap_or(backend_port), ctx.host),
+            (false, _) => {
+                if let Some(wp) = webui_port {
+                    format!("http://localhost:{}", wp)
+                } else {
+                    format!("http://localhost:{}", backend_port)
+                }
+            }
         };
 
         environments.push(UshadowEnvironment {
@@ -126,6 +235,7 @@ pub async fn discover_environments() -> Result<DiscoveryResult, String> {
             webui_port,
             running: true,
             tailscale_active,
+            host: ctx.host.clone(),
         });
     }
 
@@ -137,7 +247,75 @@ pub async fn discover_environments() -> Result<DiscoveryResult, String> {
     })
 }
 
-/// Extract port from Docker ports string
+/// Whether a container matches one `ServiceDefinition`'s `MatchRule`.
+fn matches_rule(rule: &MatchRule, name: &str, labels: &HashMap<String, String>) -> bool {
+    match rule {
+        MatchRule::Exact { value } => name == value,
+        // Also matches compose's `-1` container-index suffix.
+        MatchRule::Suffix { value } => {
+            name == value.as_str()
+                || name.ends_with(&format!("-{}", value))
+                || name.ends_with(&format!("-{}-1", value))
+        }
+        MatchRule::Label { key, value } => labels.get(key).map(|v| v == value).unwrap_or(false),
+    }
+}
+
+/// The first service definition in `registry` whose `MatchRule` matches this container, if any.
+fn matching_service<'a>(
+    registry: &'a ServiceRegistry,
+    name: &str,
+    labels: &HashMap<String, String>,
+) -> Option<&'a ServiceDefinition> {
+    registry.services.iter().find(|svc| matches_rule(&svc.match_rule, name, labels))
+}
+
+/// Whether `name`/`labels` matches any service configured in `registry`, used
+/// by the event watcher as a cheap "is this worth a refresh" filter.
+pub(crate) fn matches_any_service(name: &str, labels: &HashMap<String, String>, registry: &ServiceRegistry) -> bool {
+    matching_service(registry, name, labels).is_some()
+}
+
+/// Derive an environment's name from its backend container's name and the
+/// `MatchRule` value that matched it, e.g. `ushadow-staging-backend` with a
+/// `Suffix("backend")` rule becomes `"staging"`; an exact match, or a name with
+/// nothing but `ushadow` before the suffix (`ushadow-backend`), becomes `"default"`.
+fn derive_env_name(service: &ServiceDefinition, name: &str) -> String {
+    let matched_value = match &service.match_rule {
+        MatchRule::Suffix { value } | MatchRule::Exact { value } => Some(value.as_str()),
+        MatchRule::Label { .. } => None,
+    };
+
+    if let Some(value) = matched_value {
+        if name == value {
+            return "default".to_string();
+        }
+        for suffix in [format!("-{}-1", value), format!("-{}", value)] {
+            if let Some(stripped) = name.strip_suffix(suffix.as_str()) {
+                let env_name = stripped.trim_start_matches("ushadow-");
+                if env_name.is_empty() || env_name == "ushadow" {
+                    return "default".to_string();
+                }
+                return env_name.to_string();
+            }
+        }
+    }
+
+    name.trim_start_matches("ushadow-").to_string()
+}
+
+/// Derive a backend's webui port from its own port, per the service's configured
+/// `WebuiPortRule`, replacing the old hardcoded `backend_port - 5000` heuristic.
+fn derive_webui_port(service: &ServiceDefinition, backend_port: u16) -> Option<u16> {
+    match service.webui_port_rule {
+        Some(WebuiPortRule::Offset { offset }) => backend_port.checked_sub(offset),
+        Some(WebuiPortRule::None) | None => None,
+    }
+}
+
+/// Extract the host port from a `DockerPsEntry`'s `Ports` string.
+/// Only JSON `docker ps` lines reach this; lines that aren't JSON are skipped
+/// during discovery and are not parsed by any other route.
 fn extract_port(ports_str: &str) -> Option<u16> {
     // Format: "0.0.0.0:8000->8000/tcp" or "0.0.0.0:8050->8000/tcp"
     for part in ports_str.split(',') {
@@ -152,34 +330,36 @@ fn extract_port(ports_str: &str) -> Option<u16> {
     None
 }
 
-/// Get Tailscale URL from leader info endpoint
-fn get_tailscale_url(port: u16) -> Option<String> {
-    let url = format!("http://localhost:{}/api/unodes/leader/info", port);
+/// Extract the bare hostname from a `DOCKER_HOST` like `ssh://user@host` or
+/// `tcp://host:2375`, for querying that machine's backend directly.
+fn remote_hostname(ctx: &DockerContext) -> Option<String> {
+    let docker_host = ctx.docker_host.as_ref()?;
+    let without_scheme = docker_host.split("://").nth(1).unwrap_or(docker_host);
+    let without_userinfo = without_scheme.split('@').last().unwrap_or(without_scheme);
+    let host = without_userinfo.split(':').next().unwrap_or(without_userinfo);
+    Some(host.trim_end_matches('/').to_string())
+}
 
-    let output = Command::new("curl")
-        .args(["-s", "--connect-timeout", "1", "--max-time", "2", &url])
-        .output()
+/// Get Tailscale URL from the leader info endpoint. For a remote context this
+/// queries the remote backend directly (by host, not "localhost") rather than
+/// an SSH-tunnel port that may not exist on this machine.
+async fn get_tailscale_url(ctx: &DockerContext, port: u16) -> Option<String> {
+    let host = remote_hostname(ctx).unwrap_or_else(|| "localhost".to_string());
+    let url = format!("http://{}:{}/api/unodes/leader/info", host, port);
+
+    let client = reqwest::Client::builder()
+        .connect_timeout(Duration::from_secs(1))
+        .timeout(Duration::from_secs(2))
+        .build()
         .ok()?;
 
-    if !output.status.success() {
+    let response = client.get(&url).send().await.ok()?;
+    if !response.status().is_success() {
         return None;
     }
 
-    let stdout = String::from_utf8_lossy(&output.stdout);
-
-    // Parse JSON to extract ushadow_api_url
-    for line in stdout.split(',') {
-        if line.contains("ushadow_api_url") {
-            if let Some(start) = line.find("https://") {
-                let rest = &line[start..];
-                if let Some(end) = rest.find('"') {
-                    return Some(rest[..end].to_string());
-                }
-            }
-        }
-    }
-
-    None
+    let info: LeaderInfo = response.json().await.ok()?;
+    Some(info.ushadow_api_url)
 }
 
 #[cfg(test)]
@@ -214,6 +394,56 @@ mod tests {
         assert_eq!(extract_port("some random text"), None);
     }
 
+    #[test]
+    fn test_matches_rule_suffix_variants() {
+        let rule = MatchRule::Suffix { value: "backend".to_string() };
+        let labels = HashMap::new();
+        assert!(matches_rule(&rule, "backend", &labels));
+        assert!(matches_rule(&rule, "ushadow-staging-backend", &labels));
+        assert!(matches_rule(&rule, "ushadow-staging-backend-1", &labels));
+        assert!(!matches_rule(&rule, "ushadow-staging-frontend", &labels));
+    }
+
+    #[test]
+    fn test_matches_rule_label() {
+        let rule = MatchRule::Label { key: "ushadow.role".to_string(), value: "infra".to_string() };
+        let mut labels = HashMap::new();
+        labels.insert("ushadow.role".to_string(), "infra".to_string());
+        assert!(matches_rule(&rule, "anything", &labels));
+        assert!(!matches_rule(&rule, "anything", &HashMap::new()));
+    }
+
+    #[test]
+    fn test_derive_env_name_suffix_and_default() {
+        let service = ServiceDefinition {
+            name: "backend".to_string(),
+            display_name: "Backend".to_string(),
+            match_rule: MatchRule::Suffix { value: "backend".to_string() },
+            is_backend: true,
+            webui_port_rule: Some(WebuiPortRule::Offset { offset: 5000 }),
+        };
+        assert_eq!(derive_env_name(&service, "ushadow-staging-backend"), "staging");
+        assert_eq!(derive_env_name(&service, "ushadow-backend"), "default");
+    }
+
+    #[test]
+    fn test_derive_webui_port() {
+        let service = ServiceDefinition {
+            name: "backend".to_string(),
+            display_name: "Backend".to_string(),
+            match_rule: MatchRule::Suffix { value: "backend".to_string() },
+            is_backend: true,
+            webui_port_rule: Some(WebuiPortRule::Offset { offset: 5000 }),
+        };
+        assert_eq!(derive_webui_port(&service, 8000), Some(3000));
+
+        let no_rule_service = ServiceDefinition {
+            webui_port_rule: Some(WebuiPortRule::None),
+            ..service
+        };
+        assert_eq!(derive_webui_port(&no_rule_service, 8000), None);
+    }
+
     #[tokio::test]
     async fn test_discover_environments_runs() {
         // This test just verifies the function runs without panicking
@@ -1,7 +1,7 @@
 use std::net::TcpListener;
 use std::process::Command;
 use std::sync::Mutex;
-use tauri::State;
+use tauri::{Manager, State};
 use crate::models::{ContainerStatus, ServiceInfo};
 use super::utils::silent_command;
 
@@ -62,6 +62,16 @@ fn find_available_ports(default_backend: u16, default_webui: u16) -> (u16, u16)
 pub struct AppState {
     pub project_root: Mutex<Option<String>>,
     pub containers_running: Mutex<bool>,
+    /// Set while the `docker events` watcher spawned by `start_environment_watcher`
+    /// is running; cancelled (causing the watcher loop to exit immediately,
+    /// not just between `docker events` lines) by `stop_environment_watcher`.
+    pub environment_watcher: Mutex<Option<tokio_util::sync::CancellationToken>>,
+    /// In-flight `start-dev.sh` provisioning runs, keyed by environment name,
+    /// so `cancel_environment_creation` can kill a hung clone/build. Shared
+    /// the same way millennium-cli's dev.rs shares a spawned child: an
+    /// `Arc<Mutex<Child>>` that both the log-forwarding threads and the
+    /// cancel command can reach.
+    pub provisioning: Mutex<std::collections::HashMap<String, std::sync::Arc<std::sync::Mutex<std::process::Child>>>>,
 }
 
 impl AppState {
@@ -69,6 +79,8 @@ impl AppState {
         Self {
             project_root: Mutex::new(None),
             containers_running: Mutex::new(false),
+            environment_watcher: Mutex::new(None),
+            provisioning: Mutex::new(std::collections::HashMap::new()),
         }
     }
 }
@@ -351,44 +363,6 @@ pub fn get_container_status(state: State<AppState>) -> Result<ContainerStatus, S
     })
 }
 
-/// Check if backend API is healthy
-#[tauri::command]
-pub async fn check_backend_health(port: u16) -> Result<bool, String> {
-    let url = format!("http://localhost:{}/health", port);
-
-    let output = silent_command("curl")
-        .args(["-s", "-o", "/dev/null", "-w", "%{http_code}", "--max-time", "2", &url])
-        .output();
-
-    match output {
-        Ok(out) => {
-            let code = String::from_utf8_lossy(&out.stdout);
-            Ok(code.trim() == "200")
-        }
-        Err(_) => Ok(false),
-    }
-}
-
-/// Check if web UI is responding
-#[tauri::command]
-pub async fn check_webui_health(port: u16) -> Result<bool, String> {
-    let url = format!("http://localhost:{}", port);
-
-    let output = silent_command("curl")
-        .args(["-s", "-o", "/dev/null", "-w", "%{http_code}", "--max-time", "2", &url])
-        .output();
-
-    match output {
-        Ok(out) => {
-            let code = String::from_utf8_lossy(&out.stdout);
-            let code_num = code.trim();
-            // Accept any 2xx or 3xx response (web UI is serving)
-            Ok(code_num.starts_with('2') || code_num.starts_with('3'))
-        }
-        Err(_) => Ok(false),
-    }
-}
-
 /// Focus the main window (bring to foreground)
 #[tauri::command]
 pub fn focus_window(window: tauri::Window) -> Result<(), String> {
@@ -438,14 +412,39 @@ pub fn open_browser(url: String) -> Result<(), String> {
 
 
 
-/// Create a new environment using start-dev.sh
-/// mode: "dev" for hot-reload, "prod" for production build
+#[derive(Clone, serde::Serialize)]
+struct ProvisionLogPayload {
+    env_name: String,
+    line: String,
+}
+
+#[derive(Clone, serde::Serialize)]
+struct ProvisionDonePayload {
+    env_name: String,
+    success: bool,
+    message: String,
+}
+
+/// Start provisioning a new environment using start-dev.sh.
+/// mode: "dev" for hot-reload, "prod" for production build.
+///
+/// Unlike the old `.output()`-based version, this returns as soon as the
+/// script is spawned; progress streams to the frontend as
+/// `env-provision://log` events, and the run can be aborted with
+/// `cancel_environment_creation`.
 #[tauri::command]
-pub async fn create_environment(state: State<'_, AppState>, name: String, mode: Option<String>) -> Result<String, String> {
+pub async fn create_environment(app: tauri::AppHandle, state: State<'_, AppState>, name: String, mode: Option<String>) -> Result<String, String> {
     let root = state.project_root.lock().map_err(|e| e.to_string())?;
     let project_root = root.clone().ok_or("Project root not set")?;
     drop(root);
 
+    {
+        let provisioning = state.provisioning.lock().map_err(|e| e.to_string())?;
+        if provisioning.contains_key(&name) {
+            return Err(format!("Environment '{}' is already being provisioned", name));
+        }
+    }
+
     // Check if start-dev.sh exists
     let script_path = std::path::Path::new(&project_root).join("start-dev.sh");
     if !script_path.exists() {
@@ -464,30 +463,133 @@ pub async fn create_environment(state: State<'_, AppState>, name: String, mode:
         _ => "--dev", // Default to dev mode (hot-reload)
     };
 
-    // Run start-dev.sh in quick mode with environment name and port offset
-    let output = silent_command("bash")
+    let mut child = silent_command("bash")
         .args(["start-dev.sh", "--quick", mode_flag])
         .current_dir(&project_root)
         .env("ENV_NAME", &name)
         .env("PORT_OFFSET", port_offset.to_string())
-        .env("USHADOW_NO_BROWSER", "1")  // Custom env var we can check in script
-        .output()
+        .env("USHADOW_NO_BROWSER", "1") // Custom env var we can check in script
+        .stdout(std::process::Stdio::piped())
+        .stderr(std::process::Stdio::piped())
+        .spawn()
         .map_err(|e| format!("Failed to run start-dev.sh: {}", e))?;
 
-    if !output.status.success() {
-        let stderr = String::from_utf8_lossy(&output.stderr);
-        let stdout = String::from_utf8_lossy(&output.stdout);
-        let error_msg = if !stderr.is_empty() { stderr.to_string() } else { stdout.to_string() };
-        return Err(format!("Failed to start environment: {}", error_msg.lines().last().unwrap_or(&error_msg)));
+    // Take the pipes before the child moves into shared state, so the
+    // log-forwarding threads own them directly rather than re-locking per line.
+    let stdout = child.stdout.take();
+    let stderr = child.stderr.take();
+    let child = std::sync::Arc::new(std::sync::Mutex::new(child));
+
+    {
+        let mut provisioning = state.provisioning.lock().map_err(|e| e.to_string())?;
+        provisioning.insert(name.clone(), child.clone());
     }
 
+    let stdout_handle = spawn_log_forwarder(app.clone(), name.clone(), stdout);
+    let stderr_handle = spawn_log_forwarder(app.clone(), name.clone(), stderr);
+    spawn_provisioning_reaper(app.clone(), name.clone(), child, stdout_handle, stderr_handle);
+
     let port_info = if port_offset > 0 {
         format!(" (ports: backend={}, webui={})", backend_port, webui_port)
     } else {
         String::new()
     };
 
-    Ok(format!("Environment '{}' started{}", name, port_info))
+    Ok(format!("Environment '{}' provisioning started{}", name, port_info))
+}
+
+/// Spawn a worker thread that reads `pipe` line-by-line and emits each line
+/// as an `env-provision://log` event. Does nothing if `pipe` is `None` (e.g.
+/// stdout/stderr already taken by a previous call). Returns the thread's
+/// `JoinHandle` so the reaper can wait for it to drain before reaping.
+fn spawn_log_forwarder(app: tauri::AppHandle, env_name: String, pipe: Option<impl std::io::Read + Send + 'static>) -> Option<std::thread::JoinHandle<()>> {
+    let pipe = pipe?;
+    Some(std::thread::spawn(move || {
+        let reader = std::io::BufReader::new(pipe);
+        for line in std::io::BufRead::lines(reader) {
+            let Ok(line) = line else { break };
+            let _ = app.emit_all("env-provision://log", ProvisionLogPayload {
+                env_name: env_name.clone(),
+                line,
+            });
+        }
+    }))
+}
+
+/// Wait for both log-forwarding threads to drain (pipe EOF), then reap the
+/// `start-dev.sh` child so it doesn't linger as a zombie, remove it from
+/// `state.provisioning`, and tell the frontend whether it succeeded.
+///
+/// Without this, a normal (non-cancelled) provisioning run never gets its
+/// exit status collected and its `provisioning` entry is never cleared —
+/// only `cancel_environment_creation` used to clean either of those up.
+fn spawn_provisioning_reaper(
+    app: tauri::AppHandle,
+    env_name: String,
+    child: std::sync::Arc<std::sync::Mutex<std::process::Child>>,
+    stdout_handle: Option<std::thread::JoinHandle<()>>,
+    stderr_handle: Option<std::thread::JoinHandle<()>>,
+) {
+    std::thread::spawn(move || {
+        if let Some(handle) = stdout_handle {
+            let _ = handle.join();
+        }
+        if let Some(handle) = stderr_handle {
+            let _ = handle.join();
+        }
+
+        let wait_result = child.lock().map(|mut c| c.wait());
+
+        let state = app.state::<AppState>();
+        if let Ok(mut provisioning) = state.provisioning.lock() {
+            // Only remove our own entry: if `create_environment` was called
+            // again for this name after we started (which it can't be while
+            // we're still registered, since that call is now rejected), the
+            // map could otherwise hold a different run's child by the time
+            // we get here, and we'd orphan it from `cancel_environment_creation`.
+            if provisioning.get(&env_name).is_some_and(|c| std::sync::Arc::ptr_eq(c, &child)) {
+                provisioning.remove(&env_name);
+            }
+        }
+
+        let payload = match wait_result {
+            Ok(Ok(status)) if status.success() => ProvisionDonePayload {
+                env_name: env_name.clone(),
+                success: true,
+                message: "Provisioning completed".to_string(),
+            },
+            Ok(Ok(status)) => ProvisionDonePayload {
+                env_name: env_name.clone(),
+                success: false,
+                message: format!("start-dev.sh exited with {}", status),
+            },
+            Ok(Err(e)) => ProvisionDonePayload {
+                env_name: env_name.clone(),
+                success: false,
+                message: format!("Failed to wait on start-dev.sh: {}", e),
+            },
+            Err(e) => ProvisionDonePayload {
+                env_name: env_name.clone(),
+                success: false,
+                message: format!("Provisioning process lock was poisoned: {}", e),
+            },
+        };
+
+        let _ = app.emit_all("env-provision://done", payload);
+    });
+}
+
+/// Kill an in-flight `start-dev.sh` provisioning run, e.g. a hung clone or build.
+#[tauri::command]
+pub async fn cancel_environment_creation(state: State<'_, AppState>, name: String) -> Result<(), String> {
+    let child = {
+        let mut provisioning = state.provisioning.lock().map_err(|e| e.to_string())?;
+        provisioning.remove(&name).ok_or_else(|| format!("No provisioning run found for environment '{}'", name))?
+    };
+
+    child.lock().map_err(|e| e.to_string())?
+        .kill()
+        .map_err(|e| format!("Failed to kill provisioning process: {}", e))
 }
 
 #[cfg(test)]
@@ -0,0 +1,204 @@
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::Duration;
+use notify::{RecursiveMode, Watcher};
+use serde::Serialize;
+use tauri::{AppHandle, Manager, State};
+use super::docker::AppState;
+use super::utils::silent_command;
+
+/// The infra compose file, relative to the project root. Always watched if present.
+const INFRA_COMPOSE_FILE: &str = "compose/docker-compose.infra.yml";
+
+/// How long to wait after the last filesystem event before acting on it,
+/// unless the caller of `start_watching_project` overrides it.
+const DEFAULT_DEBOUNCE: Duration = Duration::from_millis(500);
+
+/// Find every compose file under `{project_root}/compose` that looks like a
+/// per-environment compose file, e.g. `docker-compose.infra.yml` or
+/// `docker-compose.staging.yml`.
+fn discover_compose_files(project_root: &str) -> Vec<PathBuf> {
+    let compose_dir = Path::new(project_root).join("compose");
+    let Ok(entries) = std::fs::read_dir(&compose_dir) else {
+        return vec![];
+    };
+
+    entries
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+        .filter(|path| {
+            path.file_name()
+                .and_then(|n| n.to_str())
+                .map(|n| n.starts_with("docker-compose.") && (n.ends_with(".yml") || n.ends_with(".yaml")))
+                .unwrap_or(false)
+        })
+        .collect()
+}
+
+/// The `docker compose -p <name>` project name a compose file corresponds to,
+/// e.g. `docker-compose.infra.yml` -> `"infra"`, `docker-compose.staging.yml` -> `"staging"`.
+fn compose_project_name(path: &Path) -> Option<String> {
+    let stem = path.file_name()?.to_str()?.strip_prefix("docker-compose.")?;
+    let stem = stem.strip_suffix(".yml").or_else(|| stem.strip_suffix(".yaml"))?;
+    Some(stem.to_string())
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct ComposeChangedPayload {
+    path: String,
+}
+
+/// Holds the live `notify` watcher so it (and its background thread) can be
+/// torn down by `stop_watching_project`. `notify::RecommendedWatcher` itself
+/// owns the OS watch handles and stops delivering events once dropped.
+pub struct ProjectWatcherState {
+    pub watcher: Mutex<Option<notify::RecommendedWatcher>>,
+    /// Whether a compose-file change should auto-trigger a restart of its project.
+    pub auto_restart: Mutex<bool>,
+    /// Debounce interval in effect for the current watch session.
+    pub debounce: Mutex<Duration>,
+}
+
+impl ProjectWatcherState {
+    pub fn new() -> Self {
+        Self {
+            watcher: Mutex::new(None),
+            auto_restart: Mutex::new(false),
+            debounce: Mutex::new(DEFAULT_DEBOUNCE),
+        }
+    }
+}
+
+/// Start watching the project's compose files for changes, emitting a
+/// debounced `compose://changed` event per file and optionally restarting
+/// the affected compose project when `auto_restart` is set.
+///
+/// Watches the infra compose file plus every per-environment compose file
+/// under `compose/`. `debounce_ms` overrides `DEFAULT_DEBOUNCE` if given.
+#[tauri::command]
+pub async fn start_watching_project(
+    app: AppHandle,
+    state: State<'_, AppState>,
+    watcher_state: State<'_, ProjectWatcherState>,
+    auto_restart: bool,
+    debounce_ms: Option<u64>,
+) -> Result<(), String> {
+    let root = state.project_root.lock().map_err(|e| e.to_string())?;
+    let project_root = root.clone().ok_or("Project root not set")?;
+    drop(root);
+
+    *watcher_state.auto_restart.lock().map_err(|e| e.to_string())? = auto_restart;
+    let debounce = debounce_ms.map(Duration::from_millis).unwrap_or(DEFAULT_DEBOUNCE);
+
+    let mut guard = watcher_state.watcher.lock().map_err(|e| e.to_string())?;
+    if guard.is_some() {
+        return Ok(()); // already watching
+    }
+    *watcher_state.debounce.lock().map_err(|e| e.to_string())? = debounce;
+
+    let (tx, rx) = std::sync::mpsc::channel();
+    let mut watcher = notify::recommended_watcher(tx)
+        .map_err(|e| format!("Failed to create file watcher: {}", e))?;
+
+    let mut compose_files = discover_compose_files(&project_root);
+    let infra_path = Path::new(&project_root).join(INFRA_COMPOSE_FILE);
+    if infra_path.exists() && !compose_files.contains(&infra_path) {
+        compose_files.push(infra_path);
+    }
+
+    let mut watched_any = false;
+    for path in &compose_files {
+        watcher
+            .watch(path, RecursiveMode::NonRecursive)
+            .map_err(|e| format!("Failed to watch {}: {}", path.display(), e))?;
+        watched_any = true;
+    }
+
+    if !watched_any {
+        return Err("No compose files found to watch".to_string());
+    }
+
+    *guard = Some(watcher);
+    drop(guard);
+
+    let project_root_for_thread = project_root.clone();
+    std::thread::spawn(move || debounce_loop(app, rx, project_root_for_thread, debounce));
+
+    Ok(())
+}
+
+/// Drop the watcher, which stops its background OS watch handles.
+#[tauri::command]
+pub async fn stop_watching_project(watcher_state: State<'_, ProjectWatcherState>) -> Result<(), String> {
+    let mut guard = watcher_state.watcher.lock().map_err(|e| e.to_string())?;
+    *guard = None;
+    Ok(())
+}
+
+/// Coalesce a burst of filesystem events per path into a single emit (and,
+/// if enabled, a single restart of the affected project) after `debounce` of quiet.
+fn debounce_loop(app: AppHandle, rx: std::sync::mpsc::Receiver<notify::Result<notify::Event>>, project_root: String, debounce: Duration) {
+    let mut pending: std::collections::HashSet<String> = std::collections::HashSet::new();
+
+    loop {
+        let event = if pending.is_empty() {
+            rx.recv().map_err(|_| std::sync::mpsc::RecvTimeoutError::Disconnected)
+        } else {
+            rx.recv_timeout(debounce)
+        };
+
+        match event {
+            Ok(Ok(event)) => {
+                for path in &event.paths {
+                    pending.insert(path.display().to_string());
+                }
+            }
+            Ok(Err(_)) => continue,
+            Err(std::sync::mpsc::RecvTimeoutError::Timeout) => {
+                for path in pending.drain() {
+                    let _ = app.emit_all("compose://changed", ComposeChangedPayload { path: path.clone() });
+                    maybe_restart(&app, &project_root, &path);
+                }
+            }
+            Err(std::sync::mpsc::RecvTimeoutError::Disconnected) => return, // watcher dropped
+        }
+    }
+}
+
+/// Restart the compose project that owns `changed_path` (e.g. `infra` for
+/// `docker-compose.infra.yml`, or an environment name for
+/// `docker-compose.<env>.yml`), if `auto_restart` is enabled.
+fn maybe_restart(app: &AppHandle, project_root: &str, changed_path: &str) {
+    let Some(watcher_state) = app.try_state::<ProjectWatcherState>() else { return };
+    let auto_restart = watcher_state.auto_restart.lock().map(|g| *g).unwrap_or(false);
+    if !auto_restart {
+        return;
+    }
+
+    let changed_path = Path::new(changed_path);
+    let Some(project_name) = compose_project_name(changed_path) else { return };
+    let Some(file_name) = changed_path.file_name().and_then(|n| n.to_str()) else { return };
+    // Reuse the extension of the file that actually changed (`.yml` or
+    // `.yaml`) instead of assuming `.yml`, since `discover_compose_files`
+    // watches both.
+    let relative_compose_path = format!("compose/{}", file_name);
+    let profile = if project_name == "infra" { "infra" } else { &project_name };
+
+    let project_root = project_root.to_string();
+    std::thread::spawn(move || {
+        let _ = silent_command("docker")
+            .args(["compose", "-p", &project_name, "down"])
+            .current_dir(&project_root)
+            .output();
+        let _ = silent_command("docker")
+            .args([
+                "compose",
+                "-f", &relative_compose_path,
+                "-p", &project_name,
+                "--profile", profile,
+                "up", "-d",
+            ])
+            .current_dir(&project_root)
+            .output();
+    });
+}
@@ -0,0 +1,121 @@
+use std::time::{Duration, Instant};
+use serde::Serialize;
+use tauri::{AppHandle, Manager, State};
+use tokio_util::sync::CancellationToken;
+use crate::models::HealthReport;
+use super::monitor::StreamState;
+
+/// Backoff schedule between retries: 250ms, 500ms, 1s, then capped at 1s.
+const BACKOFF_SCHEDULE_MS: &[u64] = &[250, 500, 1000];
+const PER_ATTEMPT_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// Probe `url` up to `max_attempts` times with exponential backoff, treating
+/// connection-refused/timeout as "not ready yet" rather than a fatal error,
+/// and `is_success` as the predicate distinguishing a healthy response.
+async fn probe(url: &str, max_attempts: u32, is_success: impl Fn(u16) -> bool) -> HealthReport {
+    let client = match reqwest::Client::builder().timeout(PER_ATTEMPT_TIMEOUT).build() {
+        Ok(c) => c,
+        Err(_) => return HealthReport { reachable: false, http_status: None, latency_ms: 0, attempts: 0 },
+    };
+
+    let mut attempts = 0u32;
+    loop {
+        attempts += 1;
+        let started = Instant::now();
+        let result = client.get(url).send().await;
+        let latency_ms = started.elapsed().as_millis() as u64;
+
+        match result {
+            Ok(response) => {
+                let status = response.status().as_u16();
+                return HealthReport {
+                    reachable: is_success(status),
+                    http_status: Some(status),
+                    latency_ms,
+                    attempts,
+                };
+            }
+            Err(_) => {
+                // Connection refused / timed out: the service may just not be up yet.
+                if attempts >= max_attempts {
+                    return HealthReport { reachable: false, http_status: None, latency_ms, attempts };
+                }
+                let delay_ms = BACKOFF_SCHEDULE_MS
+                    .get((attempts as usize) - 1)
+                    .copied()
+                    .unwrap_or(*BACKOFF_SCHEDULE_MS.last().unwrap());
+                tokio::time::sleep(Duration::from_millis(delay_ms)).await;
+            }
+        }
+    }
+}
+
+/// Check if the backend API is healthy via its `/health` endpoint.
+#[tauri::command]
+pub async fn check_backend_health(port: u16) -> Result<HealthReport, String> {
+    let url = format!("http://localhost:{}/health", port);
+    Ok(probe(&url, BACKOFF_SCHEDULE_MS.len() as u32, |status| status == 200).await)
+}
+
+/// Check if the web UI is responding. Any 2xx/3xx counts as "serving".
+#[tauri::command]
+pub async fn check_webui_health(port: u16) -> Result<HealthReport, String> {
+    let url = format!("http://localhost:{}", port);
+    Ok(probe(&url, BACKOFF_SCHEDULE_MS.len() as u32, |status| (200..400).contains(&status)).await)
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct HealthEventPayload {
+    env_name: String,
+    backend: HealthReport,
+    webui: Option<HealthReport>,
+}
+
+/// Poll backend/webui health every `interval_ms` and emit `health://report`
+/// events, so the UI can show latency/degraded states instead of a binary dot.
+/// Cancelled via `stop_health_monitor`, same as `start_monitoring`/`stream_logs`.
+#[tauri::command]
+pub async fn start_health_monitor(app: AppHandle, state: State<'_, StreamState>, env_name: String, backend_port: u16, webui_port: Option<u16>, interval_ms: u64) -> Result<(), String> {
+    let mut monitors = state.health_monitors.lock().map_err(|e| e.to_string())?;
+    if monitors.contains_key(&env_name) {
+        return Ok(()); // already monitoring
+    }
+
+    let token = CancellationToken::new();
+    monitors.insert(env_name.clone(), token.clone());
+    drop(monitors);
+
+    tokio::spawn(health_monitor_loop(app, env_name, backend_port, webui_port, interval_ms, token));
+    Ok(())
+}
+
+/// Cancel the health monitor for `env_name`, if running.
+#[tauri::command]
+pub async fn stop_health_monitor(state: State<'_, StreamState>, env_name: String) -> Result<(), String> {
+    let mut monitors = state.health_monitors.lock().map_err(|e| e.to_string())?;
+    if let Some(token) = monitors.remove(&env_name) {
+        token.cancel();
+    }
+    Ok(())
+}
+
+async fn health_monitor_loop(app: AppHandle, env_name: String, backend_port: u16, webui_port: Option<u16>, interval_ms: u64, token: CancellationToken) {
+    loop {
+        let backend = probe(&format!("http://localhost:{}/health", backend_port), 1, |s| s == 200).await;
+        let webui = match webui_port {
+            Some(port) => Some(probe(&format!("http://localhost:{}", port), 1, |s| (200..400).contains(&s)).await),
+            None => None,
+        };
+
+        let _ = app.emit_all("health://report", HealthEventPayload {
+            env_name: env_name.clone(),
+            backend,
+            webui,
+        });
+
+        tokio::select! {
+            _ = token.cancelled() => return,
+            _ = tokio::time::sleep(Duration::from_millis(interval_ms)) => {}
+        }
+    }
+}
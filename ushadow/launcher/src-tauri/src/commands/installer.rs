@@ -1,126 +1,116 @@
 use super::utils::{silent_command, shell_command};
+#[cfg(target_os = "macos")]
+use serde::{Deserialize, Serialize};
 use std::process::Command;
 
-/// Check if Homebrew is installed (macOS)
-/// Uses 'which brew' to find brew anywhere, with fallback to known paths
+/// A Homebrew install this Mac might have. Machines migrated from Intel and
+/// still running under Rosetta commonly end up with BOTH an Intel brew at
+/// `/usr/local` and an Apple Silicon brew at `/opt/homebrew` — collapsing
+/// them to a single "first match wins" path silently installs into whichever
+/// one happened to be checked first. Mirrors topgrade's `BrewVariant`.
 #[cfg(target_os = "macos")]
-pub fn check_brew_installed() -> bool {
-    // Try login shell first (silent to avoid window flash)
-    if shell_command("brew --version")
-        .output()
-        .map(|o| o.status.success())
-        .unwrap_or(false)
-    {
-        return true;
-    }
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BrewVariant {
+    /// Whatever `brew` resolves to via the login shell's PATH.
+    Path,
+    /// Intel Homebrew prefix (`/usr/local`).
+    MacIntel,
+    /// Apple Silicon Homebrew prefix (`/opt/homebrew`).
+    MacArm,
+}
 
-    // Fallback: try to find brew's actual location via which
-    if let Ok(output) = shell_command("which brew")
-        .output()
-    {
-        if output.status.success() {
-            let path = String::from_utf8_lossy(&output.stdout).trim().to_string();
-            if !path.is_empty() && std::path::Path::new(&path).exists() {
-                // Verify it's executable
-                if silent_command(&path)
-                    .args(["--version"])
-                    .output()
-                    .map(|o| o.status.success())
-                    .unwrap_or(false)
-                {
-                    return true;
-                }
-            }
+#[cfg(target_os = "macos")]
+impl BrewVariant {
+    pub fn binary_path(self) -> &'static str {
+        match self {
+            BrewVariant::Path => "brew",
+            BrewVariant::MacIntel => "/usr/local/bin/brew",
+            BrewVariant::MacArm => "/opt/homebrew/bin/brew",
         }
     }
 
-    // Last resort: try known paths directly (for fresh .pkg installs where shell profile not loaded)
-    let known_paths = [
-        "/opt/homebrew/bin/brew",      // Apple Silicon
-        "/usr/local/bin/brew",          // Intel Mac
-    ];
+    /// Whether the other well-known prefix also has a brew install, so the
+    /// label can disambiguate only when it actually matters.
+    fn other_variant_exists(self) -> bool {
+        match self {
+            BrewVariant::MacIntel => std::path::Path::new(BrewVariant::MacArm.binary_path()).exists(),
+            BrewVariant::MacArm => std::path::Path::new(BrewVariant::MacIntel.binary_path()).exists(),
+            BrewVariant::Path => false,
+        }
+    }
 
-    for path in known_paths {
-        if std::path::Path::new(path).exists() {
-            if silent_command(path)
-                .args(["--version"])
-                .output()
-                .map(|o| o.status.success())
-                .unwrap_or(false)
-            {
-                return true;
-            }
+    /// Human label for the frontend to show when letting a user pick which
+    /// brew to install into.
+    pub fn label(self) -> String {
+        match self {
+            BrewVariant::Path => "Homebrew".to_string(),
+            BrewVariant::MacIntel if self.other_variant_exists() => "Homebrew (Intel)".to_string(),
+            BrewVariant::MacArm if self.other_variant_exists() => "Homebrew (ARM)".to_string(),
+            BrewVariant::MacIntel | BrewVariant::MacArm => "Homebrew".to_string(),
         }
     }
 
-    false
+    fn works(self) -> bool {
+        let output = match self {
+            // Run through a login shell so profile-sourced PATH entries
+            // (e.g. `eval $(brew shellenv)`) are picked up, same as before.
+            BrewVariant::Path => shell_command("brew --version").output(),
+            BrewVariant::MacIntel | BrewVariant::MacArm => {
+                silent_command(self.binary_path()).arg("--version").output()
+            }
+        };
+        output.map(|o| o.status.success()).unwrap_or(false)
+    }
 }
 
-/// Get the brew executable path for this system
-/// Returns full path if brew isn't in PATH (e.g., after fresh .pkg install)
+/// Probe every known Homebrew location (both well-known prefixes, plus
+/// whatever `brew` resolves to via PATH if neither prefix has one) and
+/// return every variant that actually works.
 #[cfg(target_os = "macos")]
-pub fn get_brew_path() -> String {
-    // First check if brew is in PATH
-    if shell_command("brew --version")
-        .output()
-        .map(|o| o.status.success())
-        .unwrap_or(false)
-    {
-        return "brew".to_string();
+pub fn detect_brew_variants() -> Vec<BrewVariant> {
+    let mut found = Vec::new();
+
+    if BrewVariant::MacIntel.works() {
+        found.push(BrewVariant::MacIntel);
+    }
+    if BrewVariant::MacArm.works() {
+        found.push(BrewVariant::MacArm);
     }
 
-    // Try to find brew's actual location via which
-    if let Ok(output) = shell_command("which brew")
-        .output()
-    {
-        if output.status.success() {
-            let path = String::from_utf8_lossy(&output.stdout).trim().to_string();
-            if !path.is_empty() && std::path::Path::new(&path).exists() {
-                // Verify it works
-                if silent_command(&path)
-                    .args(["--version"])
-                    .output()
-                    .map(|o| o.status.success())
-                    .unwrap_or(false)
-                {
-                    return path;
-                }
-            }
-        }
+    if found.is_empty() && BrewVariant::Path.works() {
+        found.push(BrewVariant::Path);
     }
 
-    // Fall back to known paths
-    let known_paths = [
-        "/opt/homebrew/bin/brew",      // Apple Silicon
-        "/usr/local/bin/brew",          // Intel Mac
-    ];
+    found
+}
 
-    for path in known_paths {
-        if std::path::Path::new(path).exists() {
-            if silent_command(path)
-                .args(["--version"])
-                .output()
-                .map(|o| o.status.success())
-                .unwrap_or(false)
-            {
-                return path.to_string();
-            }
-        }
-    }
+/// Check if Homebrew is installed (macOS), in any of its known locations.
+#[cfg(target_os = "macos")]
+pub fn check_brew_installed() -> bool {
+    !detect_brew_variants().is_empty()
+}
 
-    // Last resort fallback
-    "brew".to_string()
+/// Get the brew executable path for this system.
+/// Returns full path if brew isn't in PATH (e.g., after fresh .pkg install)
+#[cfg(target_os = "macos")]
+pub fn get_brew_path() -> String {
+    detect_brew_variants()
+        .first()
+        .map(|variant| variant.binary_path().to_string())
+        .unwrap_or_else(|| BrewVariant::Path.binary_path().to_string())
 }
 
-/// Create a brew command with the correct path for this system
+/// Create a brew command targeting a specific Homebrew install.
 #[cfg(target_os = "macos")]
-fn brew_command() -> Command {
-    let brew_path = get_brew_path();
-    silent_command(&brew_path)
+fn brew_command(variant: BrewVariant) -> Command {
+    silent_command(variant.binary_path())
 }
 
 /// Install Homebrew (macOS)
-/// Downloads and opens the official Homebrew .pkg installer
+/// Downloads and opens the official Homebrew .pkg installer, falling back to
+/// the official install script (run non-interactively) when the .pkg can't
+/// be reached — common on networks that block github.com.
 #[cfg(target_os = "macos")]
 #[tauri::command]
 pub async fn install_homebrew() -> Result<String, String> {
@@ -128,12 +118,20 @@ pub async fn install_homebrew() -> Result<String, String> {
         return Ok("Homebrew is already installed".to_string());
     }
 
-    // Download the official Homebrew .pkg installer
+    match install_homebrew_pkg().await {
+        Ok(msg) => Ok(msg),
+        Err(pkg_err) => install_homebrew_script()
+            .map_err(|script_err| format!("{}. Install script also failed: {}", pkg_err, script_err)),
+    }
+}
+
+/// Download and open the official Homebrew .pkg installer.
+#[cfg(target_os = "macos")]
+async fn install_homebrew_pkg() -> Result<String, String> {
     let pkg_url = "https://github.com/Homebrew/brew/releases/download/5.0.9/Homebrew-5.0.9.pkg";
     let tmp_dir = std::env::temp_dir();
     let pkg_path = tmp_dir.join("Homebrew-5.0.9.pkg");
 
-    // Download the pkg file
     let response = reqwest::get(pkg_url)
         .await
         .map_err(|e| format!("Failed to download Homebrew installer: {}", e))?;
@@ -149,20 +147,90 @@ pub async fn install_homebrew() -> Result<String, String> {
     std::fs::write(&pkg_path, bytes)
         .map_err(|e| format!("Failed to save installer: {}", e))?;
 
-    // Open the .pkg file with the default installer
     let output = Command::new("open")
         .arg(&pkg_path)
         .output()
         .map_err(|e| format!("Failed to open installer: {}", e))?;
 
     if output.status.success() {
-        Ok(format!("Homebrew installer opened. Please follow the prompts to complete installation. The installer may require administrator access."))
+        Ok("Homebrew installer opened. Please follow the prompts to complete installation. The installer may require administrator access.".to_string())
     } else {
         let stderr = String::from_utf8_lossy(&output.stderr);
         Err(format!("Failed to open Homebrew installer: {}", stderr))
     }
 }
 
+/// Fall back to the official Homebrew install script, run non-interactively
+/// (there's no terminal to prompt in a GUI app). Honors
+/// `HOMEBREW_BREW_GIT_REMOTE`/`HOMEBREW_CORE_GIT_REMOTE` from this process's
+/// environment so users behind a restrictive firewall can point installs at
+/// a mirror instead of github.com.
+#[cfg(target_os = "macos")]
+fn install_homebrew_script() -> Result<String, String> {
+    let mut cmd = shell_command(
+        r#"/bin/bash -c "$(curl -fsSL https://raw.githubusercontent.com/Homebrew/install/HEAD/install.sh)""#,
+    );
+    cmd.env("NONINTERACTIVE", "1");
+
+    if let Ok(mirror) = std::env::var("HOMEBREW_BREW_GIT_REMOTE") {
+        cmd.env("HOMEBREW_BREW_GIT_REMOTE", mirror);
+    }
+    if let Ok(mirror) = std::env::var("HOMEBREW_CORE_GIT_REMOTE") {
+        cmd.env("HOMEBREW_CORE_GIT_REMOTE", mirror);
+    }
+
+    let output = cmd
+        .output()
+        .map_err(|e| format!("Failed to run Homebrew install script: {}", e))?;
+
+    if !output.status.success() {
+        return Err(format!(
+            "Homebrew install script failed: {}",
+            String::from_utf8_lossy(&output.stderr)
+        ));
+    }
+
+    // The freshly installed brew isn't on this process's PATH yet, and other
+    // async commands (health monitor, log watchers, other prerequisite
+    // probes) read PATH concurrently, so we can't just mutate it process-wide.
+    // Verify the install directly by its known full path instead — the
+    // `install_*_macos` commands that run afterward already do the same via
+    // `BrewVariant::MacIntel`/`MacArm`, which also bypass PATH.
+    let prefix = homebrew_install_prefix();
+    let brew_path = format!("{}/bin/brew", prefix);
+    let verified = Command::new(&brew_path)
+        .arg("--version")
+        .output()
+        .map(|o| o.status.success())
+        .unwrap_or(false);
+
+    if !verified {
+        return Err(format!(
+            "Homebrew install script exited successfully, but {} isn't runnable",
+            brew_path
+        ));
+    }
+
+    Ok(format!("Homebrew installed successfully via install script at {}", prefix))
+}
+
+/// The Homebrew install prefix for this Mac's CPU architecture, per
+/// `uname -m`: Apple Silicon installs to `/opt/homebrew`, Intel to `/usr/local`.
+#[cfg(target_os = "macos")]
+fn homebrew_install_prefix() -> &'static str {
+    let arch = Command::new("uname")
+        .arg("-m")
+        .output()
+        .map(|o| String::from_utf8_lossy(&o.stdout).trim().to_string())
+        .unwrap_or_default();
+
+    if arch == "arm64" {
+        "/opt/homebrew"
+    } else {
+        "/usr/local"
+    }
+}
+
 #[cfg(not(target_os = "macos"))]
 #[tauri::command]
 pub async fn install_homebrew() -> Result<String, String> {
@@ -182,21 +250,33 @@ pub fn check_brew() -> bool {
     }
 }
 
+/// List the Homebrew installs detected on this machine, so the frontend can
+/// let the user pick which one to install into instead of guessing.
+#[cfg(target_os = "macos")]
+#[tauri::command]
+pub fn list_brew_variants() -> Vec<BrewVariant> {
+    detect_brew_variants()
+}
+
+#[cfg(not(target_os = "macos"))]
+#[tauri::command]
+pub fn list_brew_variants() -> Vec<String> {
+    Vec::new()
+}
+
 /// Install Docker via Homebrew (macOS)
 #[cfg(target_os = "macos")]
 #[tauri::command]
-pub async fn install_docker_via_brew() -> Result<String, String> {
-    if !check_brew_installed() {
-        return Err("Homebrew is not installed".to_string());
+pub async fn install_docker_via_brew(variant: BrewVariant) -> Result<String, String> {
+    if !variant.works() {
+        return Err(format!("{} is not installed", variant.label()));
     }
 
-    let brew_path = get_brew_path();
-
     // Use osascript to run brew with administrator privileges
     // This will show the native macOS password dialog
     let script = format!(
         r#"do shell script "{} install --cask docker" with administrator privileges"#,
-        brew_path
+        variant.binary_path()
     );
 
     let output = Command::new("osascript")
@@ -220,7 +300,7 @@ pub async fn install_docker_via_brew() -> Result<String, String> {
 
 #[cfg(not(target_os = "macos"))]
 #[tauri::command]
-pub async fn install_docker_via_brew() -> Result<String, String> {
+pub async fn install_docker_via_brew(_variant: String) -> Result<String, String> {
     Err("Homebrew installation is only available on macOS".to_string())
 }
 
@@ -345,12 +425,12 @@ pub async fn install_tailscale_windows() -> Result<String, String> {
 /// Install Tailscale via Homebrew (macOS)
 #[cfg(target_os = "macos")]
 #[tauri::command]
-pub async fn install_tailscale_macos() -> Result<String, String> {
-    if !check_brew_installed() {
-        return Err("Homebrew is not installed. Please install from https://brew.sh".to_string());
+pub async fn install_tailscale_macos(variant: BrewVariant) -> Result<String, String> {
+    if !variant.works() {
+        return Err(format!("{} is not installed. Please install from https://brew.sh", variant.label()));
     }
 
-    let output = brew_command()
+    let output = brew_command(variant)
         .args(["install", "--cask", "tailscale"])
         .output()
         .map_err(|e| format!("Failed to run brew: {}", e))?;
@@ -365,7 +445,7 @@ pub async fn install_tailscale_macos() -> Result<String, String> {
 
 #[cfg(not(target_os = "macos"))]
 #[tauri::command]
-pub async fn install_tailscale_macos() -> Result<String, String> {
+pub async fn install_tailscale_macos(_variant: String) -> Result<String, String> {
     Err("macOS Tailscale installation is only available on macOS".to_string())
 }
 
@@ -404,11 +484,111 @@ pub async fn start_docker_service_linux() -> Result<String, String> {
     Err("Linux Docker service start is only available on Linux".to_string())
 }
 
+/// Run a raw shell command with elevated privileges. Prefers `pkexec` so the
+/// user gets the same native graphical prompt as `osascript`/UAC give on
+/// macOS/Windows; falls back to `sudo` for headless/no-polkit machines where
+/// it'll prompt on whatever terminal launched the app.
+#[cfg(target_os = "linux")]
+fn run_privileged_linux(cmd: &str) -> Result<String, String> {
+    let use_pkexec = Command::new("which")
+        .arg("pkexec")
+        .output()
+        .map(|o| o.status.success())
+        .unwrap_or(false);
+    let escalate = if use_pkexec { "pkexec" } else { "sudo" };
+
+    let output = Command::new(escalate)
+        .args(["sh", "-c", cmd])
+        .output()
+        .map_err(|e| format!("Failed to run {}: {}", escalate, e))?;
+
+    if output.status.success() {
+        Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+    } else {
+        Err(String::from_utf8_lossy(&output.stderr).trim().to_string())
+    }
+}
+
+/// Install Docker via the system package manager (Linux)
+#[cfg(target_os = "linux")]
+#[tauri::command]
+pub async fn install_docker_linux() -> Result<String, String> {
+    use super::command_runner::ShellCommandRunner;
+    use super::linux_install::{detect_linux_package_manager, raw_install_command};
+
+    let runner = ShellCommandRunner;
+    let pkg_mgr = detect_linux_package_manager(&runner).ok_or_else(|| {
+        "Could not detect a supported package manager (apt-get, dnf, yum, pacman, zypper)".to_string()
+    })?;
+
+    let packages: &[&str] = match pkg_mgr {
+        "apt" => &["docker.io", "docker-compose-plugin"],
+        "pacman" | "zypper" => &["docker", "docker-compose"],
+        _ => &["docker", "docker-compose-plugin"],
+    };
+
+    run_privileged_linux(&raw_install_command(pkg_mgr, packages))
+        .map(|_| "Docker installed successfully".to_string())
+}
+
+#[cfg(not(target_os = "linux"))]
+#[tauri::command]
+pub async fn install_docker_linux() -> Result<String, String> {
+    Err("Linux Docker installation is only available on Linux".to_string())
+}
+
+/// Install Git via the system package manager (Linux)
+#[cfg(target_os = "linux")]
+#[tauri::command]
+pub async fn install_git_linux() -> Result<String, String> {
+    use super::command_runner::ShellCommandRunner;
+    use super::linux_install::{detect_linux_package_manager, raw_install_command};
+
+    let runner = ShellCommandRunner;
+    let pkg_mgr = detect_linux_package_manager(&runner).ok_or_else(|| {
+        "Could not detect a supported package manager (apt-get, dnf, yum, pacman, zypper)".to_string()
+    })?;
+
+    run_privileged_linux(&raw_install_command(pkg_mgr, &["git"]))
+        .map(|_| "Git installed successfully".to_string())
+}
+
+#[cfg(not(target_os = "linux"))]
+#[tauri::command]
+pub async fn install_git_linux() -> Result<String, String> {
+    Err("Linux Git installation is only available on Linux".to_string())
+}
+
+/// Install Tailscale via its official repo (Linux)
+#[cfg(target_os = "linux")]
+#[tauri::command]
+pub async fn install_tailscale_linux() -> Result<String, String> {
+    use super::command_runner::ShellCommandRunner;
+    use super::linux_install::{detect_linux_package_manager, tailscale_repo_commands};
+
+    let runner = ShellCommandRunner;
+    let pkg_mgr = detect_linux_package_manager(&runner).ok_or_else(|| {
+        "Could not detect a supported package manager (apt-get, dnf, yum, pacman, zypper)".to_string()
+    })?;
+
+    for step in tailscale_repo_commands(pkg_mgr) {
+        run_privileged_linux(&step)?;
+    }
+
+    Ok("Tailscale installed successfully".to_string())
+}
+
+#[cfg(not(target_os = "linux"))]
+#[tauri::command]
+pub async fn install_tailscale_linux() -> Result<String, String> {
+    Err("Linux Tailscale installation is only available on Linux".to_string())
+}
+
 // ============================================
 // Project/Repository Management
 // ============================================
 
-use crate::models::ProjectStatus;
+use crate::models::{ProjectStatus, UpgradeStepResult, UpgradeStepStatus};
 use std::path::Path;
 use std::fs;
 
@@ -563,6 +743,122 @@ pub async fn update_ushadow_repo(project_dir: String) -> Result<String, String>
     }
 }
 
+/// Convert a finished `Command` output into this module's usual
+/// `Result<String, String>` shape, so every upgrade step can share one
+/// success/failure path regardless of which command produced it.
+fn command_output_result(output: std::process::Output) -> Result<String, String> {
+    if output.status.success() {
+        Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+    } else {
+        Err(String::from_utf8_lossy(&output.stderr).trim().to_string())
+    }
+}
+
+/// Wrap one step's `Result` into an `UpgradeStepResult`, keyed off whether it
+/// succeeded, without ever returning early — that's what lets `upgrade_all`
+/// keep going after a step fails.
+fn upgrade_step(name: &str, result: Result<String, String>) -> UpgradeStepResult {
+    match result {
+        Ok(output) => UpgradeStepResult {
+            step_name: name.to_string(),
+            status: UpgradeStepStatus::Success,
+            output,
+        },
+        Err(output) => UpgradeStepResult {
+            step_name: name.to_string(),
+            status: UpgradeStepStatus::Failed,
+            output,
+        },
+    }
+}
+
+/// Topgrade-style "update everything" command: runs Homebrew/winget/distro
+/// package upgrades, `docker compose pull`, and the repo update, recording
+/// each step's outcome independently so a failure in one doesn't abort the
+/// rest.
+#[tauri::command]
+pub async fn upgrade_all(project_dir: String) -> Result<Vec<UpgradeStepResult>, String> {
+    let mut results = Vec::new();
+
+    #[cfg(target_os = "macos")]
+    {
+        for variant in detect_brew_variants() {
+            results.push(upgrade_step(
+                &format!("Homebrew formulae ({})", variant.label()),
+                brew_command(variant)
+                    .arg("upgrade")
+                    .output()
+                    .map_err(|e| e.to_string())
+                    .and_then(command_output_result),
+            ));
+            results.push(upgrade_step(
+                &format!("Homebrew casks ({})", variant.label()),
+                brew_command(variant)
+                    .args(["upgrade", "--cask"])
+                    .output()
+                    .map_err(|e| e.to_string())
+                    .and_then(command_output_result),
+            ));
+        }
+    }
+
+    #[cfg(target_os = "windows")]
+    {
+        results.push(upgrade_step(
+            "winget upgrade --all",
+            silent_command("winget")
+                .args([
+                    "upgrade", "--all",
+                    "--accept-package-agreements", "--accept-source-agreements",
+                ])
+                .output()
+                .map_err(|e| e.to_string())
+                .and_then(command_output_result),
+        ));
+    }
+
+    #[cfg(target_os = "linux")]
+    {
+        use super::command_runner::ShellCommandRunner;
+        use super::linux_install::detect_linux_package_manager;
+
+        let runner = ShellCommandRunner;
+        match detect_linux_package_manager(&runner) {
+            Some(pkg_mgr) => {
+                let upgrade_cmd = match pkg_mgr {
+                    "apt" => "apt-get update && apt-get upgrade -y",
+                    "dnf" => "dnf upgrade -y",
+                    "yum" => "yum upgrade -y",
+                    "pacman" => "pacman -Syu --noconfirm",
+                    "zypper" => "zypper update -y",
+                    _ => "echo 'no supported package manager'",
+                };
+                results.push(upgrade_step("System packages", run_privileged_linux(upgrade_cmd)));
+            }
+            None => results.push(UpgradeStepResult {
+                step_name: "System packages".to_string(),
+                status: UpgradeStepStatus::Skipped,
+                output: "Could not detect a supported package manager (apt-get, dnf, yum, pacman, zypper)".to_string(),
+            }),
+        }
+    }
+
+    let compose_dir = Path::new(&project_dir).join("compose");
+    results.push(upgrade_step(
+        "docker compose pull",
+        silent_command("docker")
+            .args(["compose", "pull"])
+            .current_dir(&compose_dir)
+            .output()
+            .map_err(|e| e.to_string())
+            .and_then(command_output_result),
+    ));
+
+    results.push(upgrade_step("Update Ushadow repo", update_ushadow_repo(project_dir).await));
+
+    Ok(results)
+}
+
 /// Install Git via winget (Windows)
 #[cfg(target_os = "windows")]
 #[tauri::command]
@@ -590,12 +886,12 @@ pub async fn install_git_windows() -> Result<String, String> {
 /// Install Git via Homebrew (macOS)
 #[cfg(target_os = "macos")]
 #[tauri::command]
-pub async fn install_git_macos() -> Result<String, String> {
-    if !check_brew_installed() {
-        return Err("Homebrew is not installed. Git may already be installed via Xcode CLI tools.".to_string());
+pub async fn install_git_macos(variant: BrewVariant) -> Result<String, String> {
+    if !variant.works() {
+        return Err(format!("{} is not installed. Git may already be installed via Xcode CLI tools.", variant.label()));
     }
 
-    let output = brew_command()
+    let output = brew_command(variant)
         .args(["install", "git"])
         .output()
         .map_err(|e| format!("Failed to run brew: {}", e))?;
@@ -610,7 +906,7 @@ pub async fn install_git_macos() -> Result<String, String> {
 
 #[cfg(not(target_os = "macos"))]
 #[tauri::command]
-pub async fn install_git_macos() -> Result<String, String> {
+pub async fn install_git_macos(_variant: String) -> Result<String, String> {
     Err("macOS Git installation is only available on macOS".to_string())
 }
 
@@ -620,21 +916,8 @@ mod tests {
 
     #[test]
     #[cfg(target_os = "macos")]
-    fn test_check_brew_installed() {
-        // This test verifies that brew detection works via PATH
-        let result = check_brew_installed();
-
-        // Verify by running the command directly
-        let expected = Command::new("brew")
-            .args(["--version"])
-            .output()
-            .map(|o| o.status.success())
-            .unwrap_or(false);
-
-        assert_eq!(result, expected,
-            "check_brew_installed() returned {} but expected {}",
-            result, expected
-        );
+    fn test_check_brew_installed_matches_detected_variants() {
+        assert_eq!(check_brew_installed(), !detect_brew_variants().is_empty());
     }
 
     #[test]
@@ -649,4 +932,22 @@ mod tests {
             assert!(output.unwrap().status.success(), "brew --version should succeed");
         }
     }
+
+    #[test]
+    #[cfg(target_os = "macos")]
+    fn test_homebrew_install_prefix_matches_this_machine() {
+        let prefix = homebrew_install_prefix();
+        assert!(prefix == "/opt/homebrew" || prefix == "/usr/local");
+    }
+
+    #[test]
+    fn test_upgrade_step_maps_ok_and_err() {
+        let ok = upgrade_step("demo", Ok("done".to_string()));
+        assert_eq!(ok.status, UpgradeStepStatus::Success);
+        assert_eq!(ok.output, "done");
+
+        let err = upgrade_step("demo", Err("boom".to_string()));
+        assert_eq!(err.status, UpgradeStepStatus::Failed);
+        assert_eq!(err.output, "boom");
+    }
 }
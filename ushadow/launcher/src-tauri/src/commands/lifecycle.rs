@@ -0,0 +1,209 @@
+use std::io::{BufRead, BufReader};
+use std::net::TcpStream;
+use std::process::Stdio;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+use serde::{Deserialize, Serialize};
+use super::utils::silent_command;
+
+/// Readiness strategy for `wait_for_ready`, modeled after testcontainers'
+/// wait strategies: a service is "ready" once one of these conditions holds.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum WaitStrategy {
+    /// Poll `docker logs <name>` (a bounded tail first, then `--since` the
+    /// previous poll) until a line matches `pattern`. Does not use `--follow`.
+    LogLine { pattern: String },
+    /// Attempt a TCP connect to `127.0.0.1:<port>`.
+    Port { port: u16 },
+    /// Poll `url` until it returns HTTP 200.
+    Http { url: String },
+}
+
+const POLL_INTERVAL_MS: u64 = 250;
+
+/// Start a named container (infra service or backend) that already exists but is stopped.
+#[tauri::command]
+pub async fn start_service(name: String) -> Result<String, String> {
+    let output = silent_command("docker")
+        .args(["start", &name])
+        .output()
+        .map_err(|e| format!("Failed to start {}: {}", name, e))?;
+
+    if output.status.success() {
+        Ok(format!("Started {}", name))
+    } else {
+        Err(format!("Failed to start {}: {}", name, String::from_utf8_lossy(&output.stderr)))
+    }
+}
+
+/// Stop a named container.
+#[tauri::command]
+pub async fn stop_service(name: String) -> Result<String, String> {
+    let output = silent_command("docker")
+        .args(["stop", &name])
+        .output()
+        .map_err(|e| format!("Failed to stop {}: {}", name, e))?;
+
+    if output.status.success() {
+        Ok(format!("Stopped {}", name))
+    } else {
+        Err(format!("Failed to stop {}: {}", name, String::from_utf8_lossy(&output.stderr)))
+    }
+}
+
+/// Stop then start a named container.
+#[tauri::command]
+pub async fn restart_service(name: String) -> Result<String, String> {
+    let output = silent_command("docker")
+        .args(["restart", &name])
+        .output()
+        .map_err(|e| format!("Failed to restart {}: {}", name, e))?;
+
+    if output.status.success() {
+        Ok(format!("Restarted {}", name))
+    } else {
+        Err(format!("Failed to restart {}: {}", name, String::from_utf8_lossy(&output.stderr)))
+    }
+}
+
+/// Poll `strategy` every `POLL_INTERVAL_MS` until it succeeds or `timeout_ms` elapses.
+#[tauri::command]
+pub async fn wait_for_ready(name: String, strategy: WaitStrategy, timeout_ms: u64) -> Result<(), String> {
+    let deadline = Instant::now() + Duration::from_millis(timeout_ms);
+    // First poll has no cursor yet, so it falls back to a bounded tail; every
+    // poll after that passes `--since` the previous poll's timestamp so we
+    // never re-scan log history we've already checked.
+    let mut since: Option<u64> = None;
+
+    loop {
+        let ready = match &strategy {
+            WaitStrategy::LogLine { pattern } => {
+                let poll_time = unix_now();
+                let matched = check_log_line(&name, pattern, since)?;
+                since = Some(poll_time);
+                matched
+            }
+            WaitStrategy::Port { port } => check_port_open(*port),
+            WaitStrategy::Http { url } => check_http_ok(url).await,
+        };
+
+        if ready {
+            return Ok(());
+        }
+
+        if Instant::now() >= deadline {
+            return Err(format!(
+                "Timed out after {}ms waiting for '{}' to become ready ({:?})",
+                timeout_ms, name, strategy
+            ));
+        }
+
+        tokio::time::sleep(Duration::from_millis(POLL_INTERVAL_MS)).await;
+    }
+}
+
+fn unix_now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+/// Check container logs for `pattern`. On the first poll (`since` is `None`)
+/// this reads a bounded tail, since the line we're waiting for may already
+/// have been printed before `wait_for_ready` started polling. Every poll
+/// after that passes `--since <unix timestamp>` from the previous poll so we
+/// only scan log lines written since then, not the entire history again.
+fn check_log_line(name: &str, pattern: &str, since: Option<u64>) -> Result<bool, String> {
+    let re = regex::Regex::new(pattern).map_err(|e| format!("Invalid log pattern: {}", e))?;
+
+    let mut cmd = silent_command("docker");
+    cmd.arg("logs");
+    match since {
+        Some(ts) => {
+            cmd.args(["--since", &ts.to_string()]);
+        }
+        None => {
+            cmd.args(["--tail", "200"]);
+        }
+    }
+    let output = cmd
+        .arg(name)
+        .stderr(Stdio::piped())
+        .stdout(Stdio::piped())
+        .output()
+        .map_err(|e| format!("Failed to read logs for {}: {}", name, e))?;
+
+    let combined = format!(
+        "{}\n{}",
+        String::from_utf8_lossy(&output.stdout),
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    Ok(any_line_matches(&combined, &re))
+}
+
+/// Whether any line of `text` matches `re`. Split out of `check_log_line` so
+/// the regex-matching logic can be tested against canned text without a
+/// running Docker daemon.
+fn any_line_matches(text: &str, re: &regex::Regex) -> bool {
+    BufReader::new(text.as_bytes())
+        .lines()
+        .filter_map(|l| l.ok())
+        .any(|line| re.is_match(&line))
+}
+
+fn check_port_open(port: u16) -> bool {
+    TcpStream::connect_timeout(
+        &format!("127.0.0.1:{}", port).parse().expect("valid socket addr"),
+        Duration::from_millis(500),
+    )
+    .is_ok()
+}
+
+/// A connection refused means the service hasn't bound its port yet, not a fatal error.
+async fn check_http_ok(url: &str) -> bool {
+    let client = match reqwest::Client::builder()
+        .timeout(Duration::from_secs(2))
+        .build()
+    {
+        Ok(c) => c,
+        Err(_) => return false,
+    };
+
+    matches!(client.get(url).send().await, Ok(resp) if resp.status().as_u16() == 200)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_any_line_matches_finds_pattern() {
+        let re = regex::Regex::new("Server listening on port \\d+").unwrap();
+        let text = "Connecting to database...\nServer listening on port 8000\nReady";
+        assert!(any_line_matches(text, &re));
+    }
+
+    #[test]
+    fn test_any_line_matches_no_match() {
+        let re = regex::Regex::new("Server listening").unwrap();
+        let text = "Connecting to database...\nStill waiting...";
+        assert!(!any_line_matches(text, &re));
+    }
+
+    #[tokio::test]
+    async fn test_wait_for_ready_times_out_when_strategy_never_succeeds() {
+        // Port 1 is a reserved, always-closed port, so this strategy never succeeds.
+        let result = wait_for_ready(
+            "some-service".to_string(),
+            WaitStrategy::Port { port: 1 },
+            50,
+        )
+        .await;
+
+        let err = result.unwrap_err();
+        assert!(err.contains("Timed out after"));
+        assert!(err.contains("some-service"));
+    }
+}
@@ -0,0 +1,198 @@
+use std::collections::HashMap;
+use super::command_runner::{CommandRunner, ShellCommandRunner};
+use crate::models::LinuxInstallPlan;
+
+/// Package managers we know how to drive, checked via `which <binary>` in
+/// this order. The second element is the short name used by `package_name`/
+/// `install_command` below.
+const PACKAGE_MANAGERS: &[(&str, &str)] = &[
+    ("apt-get", "apt"),
+    ("dnf", "dnf"),
+    ("yum", "yum"),
+    ("pacman", "pacman"),
+    ("zypper", "zypper"),
+];
+
+/// Distros we've actually tested these commands against. Anything else still
+/// gets a best-effort command, just with a warning attached.
+const DEBIAN_LIKE_DISTROS: &[&str] = &["debian", "ubuntu", "linuxmint", "pop"];
+
+/// Package name for one of Ushadow's prerequisite tools, per package manager
+/// family. Falls back to the tool's own name when there's no distro-specific
+/// quirk to account for.
+fn package_name<'a>(pkg_mgr: &str, tool: &'a str) -> &'a str {
+    match (pkg_mgr, tool) {
+        ("apt", "docker") => "docker.io",
+        ("apt", "python") => "python3",
+        ("dnf", "python") => "python3",
+        ("yum", "python") => "python3",
+        ("zypper", "python") => "python3",
+        ("pacman", "python") => "python",
+        _ => match tool {
+            "docker" => "docker",
+            "git" => "git",
+            "python" => "python3",
+            other => other,
+        },
+    }
+}
+
+/// Render the shell command a user would paste to install `tool` via `pkg_mgr`.
+fn install_command(pkg_mgr: &str, tool: &str) -> String {
+    let package = package_name(pkg_mgr, tool);
+    match pkg_mgr {
+        "apt" => format!("sudo apt-get install -y {}", package),
+        "dnf" => format!("sudo dnf install -y {}", package),
+        "yum" => format!("sudo yum install -y {}", package),
+        "pacman" => format!("sudo pacman -S --noconfirm {}", package),
+        "zypper" => format!("sudo zypper install -y {}", package),
+        _ => format!("# unsupported package manager; install {} manually", package),
+    }
+}
+
+/// Render the raw (no `sudo` prefix) shell command to install one or more
+/// packages via `pkg_mgr`, for callers that escalate privileges themselves
+/// (see `run_privileged_linux` in `installer.rs`) rather than expecting the
+/// user to paste a `sudo ...` line into their own terminal.
+pub(crate) fn raw_install_command(pkg_mgr: &str, packages: &[&str]) -> String {
+    let joined = packages.join(" ");
+    match pkg_mgr {
+        "apt" => format!("apt-get install -y {}", joined),
+        "dnf" => format!("dnf install -y {}", joined),
+        "yum" => format!("yum install -y {}", joined),
+        "pacman" => format!("pacman -S --noconfirm {}", joined),
+        "zypper" => format!("zypper install -y {}", joined),
+        _ => format!("# unsupported package manager; install {} manually", joined),
+    }
+}
+
+/// Tailscale isn't in any distro's default repos, so installing it needs its
+/// official repo added first. Returns the sequence of raw shell commands to
+/// run, in order; the universal install script is the fallback for package
+/// managers we don't have repo instructions for.
+pub(crate) fn tailscale_repo_commands(pkg_mgr: &str) -> Vec<String> {
+    match pkg_mgr {
+        "apt" => vec![
+            "curl -fsSL https://pkgs.tailscale.com/stable/ubuntu/noble.noarmor.gpg -o /usr/share/keyrings/tailscale-archive-keyring.gpg".to_string(),
+            "curl -fsSL https://pkgs.tailscale.com/stable/ubuntu/noble.tailscale-keyring.list -o /etc/apt/sources.list.d/tailscale.list".to_string(),
+            "apt-get update".to_string(),
+            "apt-get install -y tailscale".to_string(),
+        ],
+        "dnf" | "yum" => vec![
+            format!("{} config-manager --add-repo https://pkgs.tailscale.com/stable/fedora/tailscale.repo", pkg_mgr),
+            format!("{} install -y tailscale", pkg_mgr),
+        ],
+        "zypper" => vec![
+            "zypper ar -f https://pkgs.tailscale.com/stable/opensuse/tumbleweed/tailscale.repo tailscale".to_string(),
+            "zypper install -y tailscale".to_string(),
+        ],
+        "pacman" => vec!["pacman -S --noconfirm tailscale".to_string()],
+        _ => vec!["curl -fsSL https://tailscale.com/install.sh | sh".to_string()],
+    }
+}
+
+/// Probe for a known package manager by running `which <binary>` through
+/// `runner`, in `PACKAGE_MANAGERS` order; the first one found wins.
+pub(crate) fn detect_linux_package_manager(runner: &dyn CommandRunner) -> Option<&'static str> {
+    for (binary, name) in PACKAGE_MANAGERS {
+        if matches!(runner.run(&["which", binary]), Ok((status, _, _)) if status.success()) {
+            return Some(name);
+        }
+    }
+    None
+}
+
+/// Read the `ID` field out of `/etc/os-release`, e.g. "ubuntu" or "fedora".
+fn detect_distro_id() -> Option<String> {
+    let os_release = std::fs::read_to_string("/etc/os-release").ok()?;
+    for line in os_release.lines() {
+        if let Some(value) = line.strip_prefix("ID=") {
+            return Some(value.trim_matches('"').to_string());
+        }
+    }
+    None
+}
+
+/// Build install commands for each missing Linux prerequisite. `missing_tools`
+/// should use Ushadow's own tool names ("docker", "git", "python").
+#[cfg(target_os = "linux")]
+#[tauri::command]
+pub fn get_linux_install_plan(missing_tools: Vec<String>) -> Result<LinuxInstallPlan, String> {
+    let runner = ShellCommandRunner;
+    let pkg_mgr = detect_linux_package_manager(&runner);
+    let distro = detect_distro_id();
+
+    let commands = match pkg_mgr {
+        Some(pkg_mgr) => missing_tools
+            .iter()
+            .map(|tool| (tool.clone(), install_command(pkg_mgr, tool)))
+            .collect(),
+        None => HashMap::new(),
+    };
+
+    let warning = match (pkg_mgr, &distro) {
+        (None, _) => Some(
+            "Could not detect a supported package manager (apt, dnf, yum, pacman, zypper); please install these manually.".to_string(),
+        ),
+        (Some(_), Some(id)) if !DEBIAN_LIKE_DISTROS.contains(&id.as_str()) => Some(format!(
+            "Detected distro \"{}\" isn't one we've tested against; the install commands below are best-effort.",
+            id
+        )),
+        (Some(_), None) => Some(
+            "Couldn't read /etc/os-release to confirm the distro; the install commands below are best-effort.".to_string(),
+        ),
+        _ => None,
+    };
+
+    Ok(LinuxInstallPlan {
+        package_manager: pkg_mgr.map(|s| s.to_string()),
+        distro,
+        commands,
+        warning,
+    })
+}
+
+#[cfg(not(target_os = "linux"))]
+#[tauri::command]
+pub fn get_linux_install_plan(_missing_tools: Vec<String>) -> Result<LinuxInstallPlan, String> {
+    Err("Linux install command generation is only available on Linux".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_install_command_apt() {
+        assert_eq!(install_command("apt", "docker"), "sudo apt-get install -y docker.io");
+        assert_eq!(install_command("apt", "git"), "sudo apt-get install -y git");
+    }
+
+    #[test]
+    fn test_install_command_pacman() {
+        assert_eq!(install_command("pacman", "git"), "sudo pacman -S --noconfirm git");
+        assert_eq!(install_command("pacman", "python"), "sudo pacman -S --noconfirm python");
+    }
+
+    #[test]
+    fn test_package_name_falls_back_to_tool_name() {
+        assert_eq!(package_name("dnf", "git"), "git");
+    }
+
+    #[test]
+    fn test_raw_install_command_has_no_sudo_prefix() {
+        assert_eq!(
+            raw_install_command("apt", &["docker.io", "docker-compose-plugin"]),
+            "apt-get install -y docker.io docker-compose-plugin"
+        );
+        assert_eq!(raw_install_command("yum", &["git"]), "yum install -y git");
+    }
+
+    #[test]
+    fn test_tailscale_repo_commands_fallback_to_install_script() {
+        assert_eq!(
+            tailscale_repo_commands("unknown"),
+            vec!["curl -fsSL https://tailscale.com/install.sh | sh".to_string()]
+        );
+    }
+}
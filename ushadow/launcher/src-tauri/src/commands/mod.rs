@@ -2,9 +2,32 @@ mod docker;
 mod discovery;
 mod prerequisites;
 mod installer;
+mod lifecycle;
+mod watcher;
+mod registry;
+mod monitor;
+mod autolaunch;
+mod fswatch;
+mod updater;
+mod health;
+mod command_runner;
+mod linux_install;
+mod preflight;
+mod ensure;
 mod utils;
 
 pub use docker::*;
 pub use discovery::*;
 pub use prerequisites::*;
 pub use installer::*;
+pub use lifecycle::*;
+pub use watcher::*;
+pub use registry::*;
+pub use monitor::*;
+pub use autolaunch::*;
+pub use fswatch::*;
+pub use updater::*;
+pub use health::*;
+pub use linux_install::*;
+pub use preflight::*;
+pub use ensure::*;
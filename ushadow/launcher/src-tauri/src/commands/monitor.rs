@@ -0,0 +1,201 @@
+use std::collections::HashMap;
+use std::io::{BufRead, BufReader};
+use std::process::Stdio;
+use std::sync::Mutex;
+use std::time::Duration;
+use serde::Serialize;
+use tauri::{AppHandle, Manager, State};
+use tokio_util::sync::CancellationToken;
+use crate::models::ServiceInfo;
+use super::utils::silent_command;
+
+/// Tokens for the background tasks this module spawns, keyed by environment
+/// name, so they can be cancelled individually (e.g. on window close).
+pub struct StreamState {
+    pub status_monitors: Mutex<HashMap<String, CancellationToken>>,
+    pub log_streams: Mutex<HashMap<String, CancellationToken>>,
+    pub health_monitors: Mutex<HashMap<String, CancellationToken>>,
+}
+
+impl StreamState {
+    pub fn new() -> Self {
+        Self {
+            status_monitors: Mutex::new(HashMap::new()),
+            log_streams: Mutex::new(HashMap::new()),
+            health_monitors: Mutex::new(HashMap::new()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct StatusChangedPayload {
+    env_name: String,
+    services: Vec<ServiceInfo>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct LogLinePayload {
+    env_name: String,
+    line: String,
+}
+
+const POLL_INTERVAL: Duration = Duration::from_secs(2);
+
+/// Start polling `docker compose ps` for `env_name`'s project directory and
+/// emitting `container://status-changed` whenever the service list differs
+/// from the previous poll, so the frontend can stop polling status itself.
+#[tauri::command]
+pub async fn start_monitoring(app: AppHandle, state: State<'_, StreamState>, env_name: String, project_dir: String) -> Result<(), String> {
+    let mut monitors = state.status_monitors.lock().map_err(|e| e.to_string())?;
+    if monitors.contains_key(&env_name) {
+        return Ok(()); // already monitoring
+    }
+
+    let token = CancellationToken::new();
+    monitors.insert(env_name.clone(), token.clone());
+    drop(monitors);
+
+    tokio::spawn(monitor_loop(app, env_name, project_dir, token));
+    Ok(())
+}
+
+/// Stop the status monitor for `env_name`, if running.
+#[tauri::command]
+pub async fn stop_monitoring(state: State<'_, StreamState>, env_name: String) -> Result<(), String> {
+    let mut monitors = state.status_monitors.lock().map_err(|e| e.to_string())?;
+    if let Some(token) = monitors.remove(&env_name) {
+        token.cancel();
+    }
+    Ok(())
+}
+
+async fn monitor_loop(app: AppHandle, env_name: String, project_dir: String, token: CancellationToken) {
+    let mut previous: Option<Vec<ServiceInfo>> = None;
+
+    loop {
+        tokio::select! {
+            _ = token.cancelled() => return,
+            _ = tokio::time::sleep(POLL_INTERVAL) => {}
+        }
+
+        let services = match compose_ps(&project_dir) {
+            Ok(s) => s,
+            Err(_) => continue, // transient docker/compose failure; keep polling
+        };
+
+        if previous.as_ref().map(|p| !services_eq(p, &services)).unwrap_or(true) {
+            let _ = app.emit_all("container://status-changed", StatusChangedPayload {
+                env_name: env_name.clone(),
+                services: services.clone(),
+            });
+            previous = Some(services);
+        }
+    }
+}
+
+fn services_eq(a: &[ServiceInfo], b: &[ServiceInfo]) -> bool {
+    a.len() == b.len()
+        && a.iter().zip(b.iter()).all(|(x, y)| x.name == y.name && x.status == y.status && x.ports == y.ports)
+}
+
+fn compose_ps(project_dir: &str) -> Result<Vec<ServiceInfo>, String> {
+    let output = silent_command("docker")
+        .args(["compose", "ps", "--format", "{{.Name}}\t{{.Status}}\t{{.Ports}}"])
+        .current_dir(project_dir)
+        .output()
+        .map_err(|e| format!("Failed to get compose status: {}", e))?;
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    Ok(stdout
+        .lines()
+        .filter_map(|line| {
+            let parts: Vec<&str> = line.split('\t').collect();
+            if parts.len() < 2 {
+                return None;
+            }
+            Some(ServiceInfo {
+                name: parts[0].to_string(),
+                status: parts[1].to_string(),
+                ports: parts.get(2).map(|s| s.to_string()),
+            })
+        })
+        .collect())
+}
+
+/// Tail `docker compose logs -f` for `env_name`'s project and emit each line
+/// as a `container://log-line` event, until cancelled via `stop_log_stream`.
+#[tauri::command]
+pub async fn stream_logs(app: AppHandle, state: State<'_, StreamState>, env_name: String, project_dir: String) -> Result<(), String> {
+    let mut streams = state.log_streams.lock().map_err(|e| e.to_string())?;
+    if streams.contains_key(&env_name) {
+        return Ok(()); // already streaming
+    }
+
+    let token = CancellationToken::new();
+    streams.insert(env_name.clone(), token.clone());
+    drop(streams);
+
+    tokio::spawn(log_stream_loop(app, env_name, project_dir, token));
+    Ok(())
+}
+
+/// Cancel the log stream for `env_name`, if running.
+#[tauri::command]
+pub async fn stop_log_stream(state: State<'_, StreamState>, env_name: String) -> Result<(), String> {
+    let mut streams = state.log_streams.lock().map_err(|e| e.to_string())?;
+    if let Some(token) = streams.remove(&env_name) {
+        token.cancel();
+    }
+    Ok(())
+}
+
+async fn log_stream_loop(app: AppHandle, env_name: String, project_dir: String, token: CancellationToken) {
+    let mut child = match silent_command("docker")
+        .args(["compose", "logs", "-f", "--tail", "100"])
+        .current_dir(&project_dir)
+        .stdout(Stdio::piped())
+        .spawn()
+    {
+        Ok(child) => child,
+        Err(_) => return,
+    };
+
+    let Some(stdout) = child.stdout.take() else { return };
+    let reader = BufReader::new(stdout);
+
+    // Line reads are blocking, so do them on a worker thread and forward
+    // results over a channel the async loop can select on alongside cancellation.
+    let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+    std::thread::spawn(move || {
+        for line in reader.lines() {
+            match line {
+                Ok(l) => {
+                    if tx.send(l).is_err() {
+                        break;
+                    }
+                }
+                Err(_) => break,
+            }
+        }
+    });
+
+    loop {
+        tokio::select! {
+            _ = token.cancelled() => {
+                let _ = child.kill();
+                return;
+            }
+            line = rx.recv() => {
+                match line {
+                    Some(line) => {
+                        let _ = app.emit_all("container://log-line", LogLinePayload {
+                            env_name: env_name.clone(),
+                            line,
+                        });
+                    }
+                    None => return, // process exited
+                }
+            }
+        }
+    }
+}
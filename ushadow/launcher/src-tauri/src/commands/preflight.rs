@@ -0,0 +1,191 @@
+use std::future::Future;
+use std::pin::Pin;
+use serde::{Deserialize, Serialize};
+use super::command_runner::ShellCommandRunner;
+use super::prerequisites::{
+    check_docker, check_git, check_tailscale, meets_minimum, version_tuple_str,
+    DOCKER_MIN_VERSION, GIT_MIN_VERSION,
+};
+
+/// Outcome of one preflight check, distinguishing a hard blocker from a
+/// warning the user can still proceed past. Modeled on Fuchsia's `ffx
+/// preflight`, which makes the same success/warning/failure distinction
+/// instead of collapsing everything to a boolean.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "status", rename_all = "snake_case")]
+pub enum PreflightResult {
+    Success(String),
+    Warning(String),
+    Failure {
+        message: String,
+        /// The Tauri command the frontend should offer as a one-click fix,
+        /// e.g. `"install_docker_via_brew"`. `None` when there isn't one.
+        remediation: Option<String>,
+    },
+}
+
+/// One check in the preflight panel. Implementations own their own
+/// probing/version logic; `run_preflight` just collects the results.
+pub trait PreflightCheck: Send + Sync {
+    fn name(&self) -> &'static str;
+    fn run<'a>(&'a self) -> Pin<Box<dyn Future<Output = PreflightResult> + Send + 'a>>;
+}
+
+pub struct DockerCheck;
+
+impl PreflightCheck for DockerCheck {
+    fn name(&self) -> &'static str {
+        "docker"
+    }
+
+    fn run<'a>(&'a self) -> Pin<Box<dyn Future<Output = PreflightResult> + Send + 'a>> {
+        Box::pin(async move {
+            let runner = ShellCommandRunner;
+            let (installed, running, version) = check_docker(&runner);
+
+            if !installed {
+                return PreflightResult::Failure {
+                    message: "Docker is not installed".to_string(),
+                    remediation: Some("install_docker_via_brew".to_string()),
+                };
+            }
+            if !running {
+                return PreflightResult::Failure {
+                    message: "Docker is installed but the daemon isn't running".to_string(),
+                    remediation: Some("start_docker_desktop_macos".to_string()),
+                };
+            }
+            if !meets_minimum(version.as_deref(), DOCKER_MIN_VERSION) {
+                return PreflightResult::Warning(format!(
+                    "Docker {} is older than the recommended {}+",
+                    version.unwrap_or_default(),
+                    version_tuple_str(DOCKER_MIN_VERSION)
+                ));
+            }
+
+            PreflightResult::Success(format!("Docker {} is ready", version.unwrap_or_default()))
+        })
+    }
+}
+
+pub struct TailscaleCheck;
+
+impl PreflightCheck for TailscaleCheck {
+    fn name(&self) -> &'static str {
+        "tailscale"
+    }
+
+    fn run<'a>(&'a self) -> Pin<Box<dyn Future<Output = PreflightResult> + Send + 'a>> {
+        Box::pin(async move {
+            let runner = ShellCommandRunner;
+            let (installed, connected, version) = check_tailscale(&runner);
+
+            if !installed {
+                return PreflightResult::Failure {
+                    message: "Tailscale is not installed".to_string(),
+                    remediation: Some("install_tailscale_macos".to_string()),
+                };
+            }
+            if !connected {
+                return PreflightResult::Warning(
+                    "Tailscale is installed but not connected; remote environments won't be discovered".to_string(),
+                );
+            }
+
+            PreflightResult::Success(format!(
+                "Tailscale {} is connected",
+                version.unwrap_or_default()
+            ))
+        })
+    }
+}
+
+pub struct GitCheck;
+
+impl PreflightCheck for GitCheck {
+    fn name(&self) -> &'static str {
+        "git"
+    }
+
+    fn run<'a>(&'a self) -> Pin<Box<dyn Future<Output = PreflightResult> + Send + 'a>> {
+        Box::pin(async move {
+            let runner = ShellCommandRunner;
+            let (installed, version) = check_git(&runner);
+
+            if !installed {
+                return PreflightResult::Failure {
+                    message: "Git is not installed".to_string(),
+                    remediation: Some("install_git_macos".to_string()),
+                };
+            }
+            if !meets_minimum(version.as_deref(), GIT_MIN_VERSION) {
+                return PreflightResult::Warning(format!(
+                    "Git {} is older than the recommended {}+",
+                    version.unwrap_or_default(),
+                    version_tuple_str(GIT_MIN_VERSION)
+                ));
+            }
+
+            PreflightResult::Success(format!("Git {} is ready", version.unwrap_or_default()))
+        })
+    }
+}
+
+fn preflight_checks() -> Vec<Box<dyn PreflightCheck>> {
+    vec![Box::new(DockerCheck), Box::new(TailscaleCheck), Box::new(GitCheck)]
+}
+
+/// Run every registered preflight check and collect their results, so the
+/// frontend can render per-check severity with an actionable fix button
+/// instead of a single pass/fail gate.
+#[tauri::command]
+pub async fn run_preflight() -> Result<Vec<PreflightResult>, String> {
+    let mut results = Vec::new();
+    for check in preflight_checks() {
+        results.push(check.run().await);
+    }
+    Ok(results)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::super::command_runner::env_test_lock;
+
+    #[tokio::test]
+    async fn test_run_preflight_returns_one_result_per_check() {
+        let results = run_preflight().await.unwrap();
+        assert_eq!(results.len(), preflight_checks().len());
+    }
+
+    #[tokio::test]
+    async fn test_docker_check_failure_has_remediation() {
+        let _guard = env_test_lock();
+        std::env::set_var("MOCK_MODE", "true");
+        std::env::set_var("MOCK_DOCKER_INSTALLED", "false");
+        let result = DockerCheck.run().await;
+        std::env::remove_var("MOCK_DOCKER_INSTALLED");
+        std::env::remove_var("MOCK_MODE");
+
+        match result {
+            PreflightResult::Failure { remediation, .. } => {
+                assert_eq!(remediation.as_deref(), Some("install_docker_via_brew"));
+            }
+            other => panic!("expected Failure, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_git_check_warns_on_old_version() {
+        let _guard = env_test_lock();
+        std::env::set_var("MOCK_MODE", "true");
+        std::env::set_var("MOCK_GIT_INSTALLED", "true");
+        std::env::set_var("MOCK_GIT_VERSION", "git version 2.10.0");
+        let result = GitCheck.run().await;
+        std::env::remove_var("MOCK_GIT_VERSION");
+        std::env::remove_var("MOCK_GIT_INSTALLED");
+        std::env::remove_var("MOCK_MODE");
+
+        assert!(matches!(result, PreflightResult::Warning(_)));
+    }
+}
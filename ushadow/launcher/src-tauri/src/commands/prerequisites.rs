@@ -1,15 +1,78 @@
-use crate::models::PrerequisiteStatus;
-use super::utils::{silent_command, shell_command};
+use crate::models::{PlatformInfo, PrerequisiteStatus, ToolStatus};
+use super::command_runner::{CommandRunner, ShellCommandRunner};
 use std::env;
+use std::sync::{mpsc, Arc};
+use std::time::Duration;
+
+/// Minimum versions we require. The UI can still show an older install as
+/// "installed" (so basic flows work) while flagging `version_ok = false`.
+pub const DOCKER_MIN_VERSION: (u32, u32, u32) = (24, 0, 0);
+pub const GIT_MIN_VERSION: (u32, u32, u32) = (2, 30, 0);
+pub const PYTHON_MIN_VERSION: (u32, u32, u32) = (3, 9, 0);
+
+pub(crate) fn version_tuple_str((major, minor, patch): (u32, u32, u32)) -> String {
+    format!("{}.{}.{}", major, minor, patch)
+}
+
+/// Extract the first `MAJOR.MINOR[.PATCH]` triple found in `text`, e.g. from
+/// "Docker version 24.0.0, build abc", "git version 2.40.0", "Python 3.11.0",
+/// or Tailscale's bare "1.56.0". A missing patch component is treated as 0.
+/// Parsing for each component stops at the first non-digit character, so
+/// suffixes like "24.0.0-rc1" or "(MOCKED)" don't break it.
+fn parse_version(text: &str) -> Option<(u32, u32, u32)> {
+    let digits_prefix = |s: &str| -> Option<u32> {
+        let digits: String = s.chars().take_while(|c| c.is_ascii_digit()).collect();
+        if digits.is_empty() { None } else { digits.parse().ok() }
+    };
+
+    for word in text.split(|c: char| c.is_whitespace()) {
+        // A candidate version token looks like `\d+\.\d+(\.\d+)?` possibly followed by junk.
+        let mut parts = word.splitn(3, '.');
+        let major = parts.next().and_then(digits_prefix);
+        let minor = parts.next().and_then(digits_prefix);
+        let patch = parts.next().and_then(digits_prefix).unwrap_or(0);
+
+        if let (Some(major), Some(minor)) = (major, minor) {
+            return Some((major, minor, patch));
+        }
+    }
+    None
+}
+
+/// Whether `version` (extracted from a tool's raw `--version` output) meets `minimum`.
+pub(crate) fn meets_minimum(version: Option<&str>, minimum: (u32, u32, u32)) -> bool {
+    match version.and_then(parse_version) {
+        Some(parsed) => parsed >= minimum,
+        None => false,
+    }
+}
 
 /// Check if we're in mock mode
 fn is_mock_mode() -> bool {
     env::var("MOCK_MODE").unwrap_or_default() == "true"
 }
 
-/// Check if Docker is installed and running
-/// Tries login shell first, then falls back to known paths
-pub fn check_docker() -> (bool, bool, Option<String>) {
+/// Mock-mode override for a tool's reported version, so tests can exercise
+/// both the pass and fail paths of `meets_minimum` deterministically.
+fn mock_version_override(tool: &str, default: &str) -> String {
+    env::var(format!("MOCK_{}_VERSION", tool)).unwrap_or_else(|_| default.to_string())
+}
+
+/// Extra directories to search for a tool's binary, beyond the built-in
+/// known-path fallbacks, as a `:`-separated list (mirroring `PATH`). Lets
+/// users on nonstandard installs (custom Homebrew prefix, etc) point us at
+/// their binary without waiting on a new release.
+fn extra_search_dirs(var: &str) -> Vec<String> {
+    env::var(var)
+        .map(|paths| paths.split(':').filter(|p| !p.is_empty()).map(|p| p.to_string()).collect())
+        .unwrap_or_default()
+}
+
+/// Check if Docker is installed and running.
+/// Tries `runner` first, then (for the real shell runner) falls back to known paths.
+/// Takes a `&dyn CommandRunner` so tests can supply canned output instead of
+/// depending on whatever happens to be installed on the test machine.
+pub fn check_docker(runner: &dyn CommandRunner) -> (bool, bool, Option<String>) {
     use std::path::Path;
 
     // Mock mode for testing
@@ -17,40 +80,56 @@ pub fn check_docker() -> (bool, bool, Option<String>) {
         let installed = env::var("MOCK_DOCKER_INSTALLED").unwrap_or_default() == "true";
         let running = env::var("MOCK_DOCKER_RUNNING").unwrap_or_default() == "true";
         let version = if installed {
-            Some("Docker version 24.0.0 (MOCKED)".to_string())
+            Some(mock_version_override("DOCKER", "Docker version 24.0.0 (MOCKED)"))
         } else {
             None
         };
         return (installed, running, version);
     }
 
-    // Try login shell first (silent to avoid window flash on Windows)
-    let version_output = shell_command("docker --version")
-        .output();
+    // A user-configured binary wins outright and is run directly, skipping
+    // the default name and known-path fallbacks below.
+    if let Ok(custom_binary) = env::var("USHADOW_DOCKER_BINARY") {
+        return match runner.run(&[&custom_binary, "--version"]) {
+            Ok((status, stdout, _)) if status.success() => {
+                let version = Some(stdout.trim().to_string());
+                let running = matches!(runner.run(&[&custom_binary, "info"]), Ok((status, _, _)) if status.success());
+                (true, running, version)
+            }
+            _ => (false, false, None),
+        };
+    }
+
+    let version_output = runner.run(&["docker", "--version"]);
 
     let (mut installed, mut version, mut docker_path) = match version_output {
-        Ok(output) if output.status.success() => {
-            let ver = String::from_utf8_lossy(&output.stdout).trim().to_string();
-            (true, Some(ver), "docker".to_string())
+        Ok((status, stdout, _)) if status.success() => {
+            (true, Some(stdout.trim().to_string()), "docker".to_string())
         }
         _ => (false, None, String::new()),
     };
 
-    // Fallback: check known Docker paths directly (for fresh installs)
+    // Fallback: check known Docker paths directly (for fresh installs), plus
+    // any extra directories the user pointed us at.
     if !installed {
-        let known_paths = [
-            "/usr/local/bin/docker",           // macOS Docker Desktop
-            "/opt/homebrew/bin/docker",        // Homebrew on Apple Silicon
-            "/Applications/Docker.app/Contents/Resources/bin/docker", // Docker.app direct
+        let mut known_paths = vec![
+            "/usr/local/bin/docker".to_string(),           // macOS Docker Desktop
+            "/opt/homebrew/bin/docker".to_string(),         // Homebrew on Apple Silicon
+            "/Applications/Docker.app/Contents/Resources/bin/docker".to_string(), // Docker.app direct
         ];
+        known_paths.extend(
+            extra_search_dirs("USHADOW_DOCKER_EXTRA_PATHS")
+                .into_iter()
+                .map(|dir| format!("{}/docker", dir.trim_end_matches('/'))),
+        );
 
-        for path in known_paths {
+        for path in &known_paths {
             if Path::new(path).exists() {
-                if let Ok(output) = silent_command(path).arg("--version").output() {
-                    if output.status.success() {
+                if let Ok((status, stdout, _)) = runner.run(&[path, "--version"]) {
+                    if status.success() {
                         installed = true;
-                        version = Some(String::from_utf8_lossy(&output.stdout).trim().to_string());
-                        docker_path = path.to_string();
+                        version = Some(stdout.trim().to_string());
+                        docker_path = path.clone();
                         break;
                     }
                 }
@@ -63,69 +142,47 @@ pub fn check_docker() -> (bool, bool, Option<String>) {
     }
 
     // Check if Docker daemon is running
-    let info_output = if docker_path == "docker" {
-        shell_command("docker info")
-            .output()
-    } else {
-        silent_command(&docker_path).arg("info").output()
-    };
-    let running = matches!(info_output, Ok(output) if output.status.success());
+    let running = matches!(runner.run(&[&docker_path, "info"]), Ok((status, _, _)) if status.success());
 
     (installed, running, version)
 }
 
-/// Check if Git is installed
-/// Uses bash login shell to ensure shell profile is sourced and PATH includes git
-pub fn check_git() -> (bool, Option<String>) {
+/// Check if Git is installed.
+pub fn check_git(runner: &dyn CommandRunner) -> (bool, Option<String>) {
     // Mock mode for testing
     if is_mock_mode() {
         let installed = env::var("MOCK_GIT_INSTALLED").unwrap_or_default() == "true";
         let version = if installed {
-            Some("git version 2.40.0 (MOCKED)".to_string())
+            Some(mock_version_override("GIT", "git version 2.40.0 (MOCKED)"))
         } else {
             None
         };
         return (installed, version);
     }
 
-    let version_output = shell_command("git --version")
-        .output();
-
-    match version_output {
-        Ok(output) if output.status.success() => {
-            let version = String::from_utf8_lossy(&output.stdout).trim().to_string();
-            (true, Some(version))
-        }
+    match runner.run(&["git", "--version"]) {
+        Ok((status, stdout, _)) if status.success() => (true, Some(stdout.trim().to_string())),
         _ => (false, None),
     }
 }
 
-/// Check if Tailscale is installed and connected
-/// Uses bash login shell to ensure shell profile is sourced and PATH includes tailscale
-pub fn check_tailscale() -> (bool, bool, Option<String>) {
+/// Check if Tailscale is installed and connected.
+pub fn check_tailscale(runner: &dyn CommandRunner) -> (bool, bool, Option<String>) {
     // Mock mode for testing
     if is_mock_mode() {
         let installed = env::var("MOCK_TAILSCALE_INSTALLED").unwrap_or_default() == "true";
         let connected = installed; // If installed, assume connected in mock mode
         let version = if installed {
-            Some("1.56.0 (MOCKED)".to_string())
+            Some(mock_version_override("TAILSCALE", "1.56.0 (MOCKED)"))
         } else {
             None
         };
         return (installed, connected, version);
     }
 
-    let version_output = shell_command("tailscale --version")
-        .output();
-
-    let (installed, version) = match version_output {
-        Ok(output) if output.status.success() => {
-            let version = String::from_utf8_lossy(&output.stdout)
-                .lines()
-                .next()
-                .unwrap_or("")
-                .trim()
-                .to_string();
+    let (installed, version) = match runner.run(&["tailscale", "--version"]) {
+        Ok((status, stdout, _)) if status.success() => {
+            let version = stdout.lines().next().unwrap_or("").trim().to_string();
             (true, Some(version))
         }
         _ => (false, None),
@@ -135,44 +192,48 @@ pub fn check_tailscale() -> (bool, bool, Option<String>) {
         return (false, false, None);
     }
 
-    let status_output = shell_command("tailscale status")
-        .output();
-    let connected = matches!(status_output, Ok(output) if output.status.success());
+    let connected = matches!(runner.run(&["tailscale", "status"]), Ok((status, _, _)) if status.success());
 
     (installed, connected, version)
 }
 
-/// Check if Python 3 is installed
-/// Uses bash login shell to ensure shell profile is sourced and PATH includes python
-pub fn check_python() -> (bool, Option<String>) {
+/// Check if Python 3 is installed.
+pub fn check_python(runner: &dyn CommandRunner) -> (bool, Option<String>) {
     // Mock mode for testing
     if is_mock_mode() {
         let installed = env::var("MOCK_PYTHON_INSTALLED").unwrap_or_default() == "true";
         let version = if installed {
-            Some("Python 3.11.0 (MOCKED)".to_string())
+            Some(mock_version_override("PYTHON", "Python 3.11.0 (MOCKED)"))
         } else {
             None
         };
         return (installed, version);
     }
 
-    // Try python3 first (recommended)
-    let version_output = shell_command("python3 --version")
-        .output();
+    // A user-configured binary wins outright; still require it to report
+    // Python 3, same as the `python` fallback below.
+    if let Ok(custom_binary) = env::var("USHADOW_PYTHON_BINARY") {
+        return match runner.run(&[&custom_binary, "--version"]) {
+            Ok((status, stdout, _)) if status.success() => {
+                let version = stdout.trim().to_string();
+                if version.starts_with("Python 3") {
+                    (true, Some(version))
+                } else {
+                    (false, None)
+                }
+            }
+            _ => (false, None),
+        };
+    }
 
-    match version_output {
-        Ok(output) if output.status.success() => {
-            let version = String::from_utf8_lossy(&output.stdout).trim().to_string();
-            (true, Some(version))
-        }
+    // Try python3 first (recommended)
+    match runner.run(&["python3", "--version"]) {
+        Ok((status, stdout, _)) if status.success() => (true, Some(stdout.trim().to_string())),
         _ => {
             // Fallback to python (might be Python 2)
-            let version_output = shell_command("python --version")
-                .output();
-
-            match version_output {
-                Ok(output) if output.status.success() => {
-                    let version = String::from_utf8_lossy(&output.stdout).trim().to_string();
+            match runner.run(&["python", "--version"]) {
+                Ok((status, stdout, _)) if status.success() => {
+                    let version = stdout.trim().to_string();
                     // Only count as installed if it's Python 3
                     if version.starts_with("Python 3") {
                         (true, Some(version))
@@ -186,58 +247,303 @@ pub fn check_python() -> (bool, Option<String>) {
     }
 }
 
+/// The result of probing one tool, in the generic shape every `ToolCheck`
+/// probe function returns, regardless of how it got there (custom binary,
+/// known-path fallback, etc all collapse to this by the time the registry sees it).
+struct ToolProbeResult {
+    installed: bool,
+    version: Option<String>,
+    /// Daemon/VPN connectivity, for tools that have such a concept.
+    connected: Option<bool>,
+}
+
+/// One entry in the prerequisite tool registry: what to probe and the
+/// minimum version to enforce. Adding a new tool (Node, kubectl, Rust, ...)
+/// is just adding an entry here instead of hand-writing another `check_*`
+/// function and wiring it through `check_prerequisites` by hand.
+struct ToolCheck {
+    name: &'static str,
+    min_version: (u32, u32, u32),
+    probe: fn(&dyn CommandRunner) -> ToolProbeResult,
+}
+
+/// The registry of tools Ushadow depends on. Each existing hand-written
+/// `check_*` function becomes a single entry here; the engine below is what
+/// actually drives them in parallel with a timeout.
+fn tool_registry() -> Vec<ToolCheck> {
+    vec![
+        ToolCheck {
+            name: "docker",
+            min_version: DOCKER_MIN_VERSION,
+            probe: |runner| {
+                let (installed, running, version) = check_docker(runner);
+                ToolProbeResult { installed, version, connected: Some(running) }
+            },
+        },
+        ToolCheck {
+            name: "git",
+            min_version: GIT_MIN_VERSION,
+            probe: |runner| {
+                let (installed, version) = check_git(runner);
+                ToolProbeResult { installed, version, connected: None }
+            },
+        },
+        ToolCheck {
+            name: "tailscale",
+            min_version: (0, 0, 0),
+            probe: |runner| {
+                let (installed, connected, version) = check_tailscale(runner);
+                ToolProbeResult { installed, version, connected: Some(connected) }
+            },
+        },
+        ToolCheck {
+            name: "python",
+            min_version: PYTHON_MIN_VERSION,
+            probe: |runner| {
+                let (installed, version) = check_python(runner);
+                ToolProbeResult { installed, version, connected: None }
+            },
+        },
+    ]
+}
+
+/// How long a single tool probe gets before we give up on it rather than
+/// stalling the whole panel on, say, a hung `docker info`.
+const TOOL_CHECK_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Run every tool in `registry` concurrently (one thread per tool) against
+/// `runner`, each bounded by `TOOL_CHECK_TIMEOUT`. A tool that times out is
+/// reported as not installed rather than blocking the others.
+fn run_tool_checks(runner: Arc<dyn CommandRunner>) -> Vec<ToolStatus> {
+    // Spawn every probe before waiting on any of them.
+    let pending: Vec<_> = tool_registry()
+        .into_iter()
+        .map(|check| {
+            let runner = Arc::clone(&runner);
+            let probe = check.probe;
+            let (tx, rx) = mpsc::channel();
+            std::thread::spawn(move || {
+                let _ = tx.send(probe(runner.as_ref()));
+            });
+            (check, rx)
+        })
+        .collect();
+
+    pending
+        .into_iter()
+        .map(|(check, rx)| {
+            let result = rx.recv_timeout(TOOL_CHECK_TIMEOUT).unwrap_or(ToolProbeResult {
+                installed: false,
+                version: None,
+                connected: None,
+            });
+
+            ToolStatus {
+                name: check.name.to_string(),
+                installed: result.installed,
+                version_ok: meets_minimum(result.version.as_deref(), check.min_version),
+                version: result.version,
+                min_version: version_tuple_str(check.min_version),
+                connected: result.connected,
+            }
+        })
+        .collect()
+}
+
+/// Get the status of every tool in the prerequisite registry (Docker, Git,
+/// Tailscale, Python, and anything else added to `tool_registry`), probed in
+/// parallel so one hung check can't stall the others.
+#[tauri::command]
+pub fn check_tools() -> Result<Vec<ToolStatus>, String> {
+    Ok(run_tool_checks(Arc::new(ShellCommandRunner)))
+}
+
 /// Get full prerequisite status
 #[tauri::command]
 pub fn check_prerequisites() -> Result<PrerequisiteStatus, String> {
-    let (docker_installed, docker_running, docker_version) = check_docker();
-    let (tailscale_installed, tailscale_connected, tailscale_version) = check_tailscale();
-    let (git_installed, git_version) = check_git();
-    let (python_installed, python_version) = check_python();
+    let runner = ShellCommandRunner;
+    let (docker_installed, docker_running, docker_version) = check_docker(&runner);
+    let (tailscale_installed, tailscale_connected, tailscale_version) = check_tailscale(&runner);
+    let (git_installed, git_version) = check_git(&runner);
+    let (python_installed, python_version) = check_python(&runner);
+
+    let docker_version_ok = meets_minimum(docker_version.as_deref(), DOCKER_MIN_VERSION);
+    let git_version_ok = meets_minimum(git_version.as_deref(), GIT_MIN_VERSION);
+    let python_version_ok = meets_minimum(python_version.as_deref(), PYTHON_MIN_VERSION);
 
     Ok(PrerequisiteStatus {
         docker_installed,
         docker_running,
+        docker_version_ok,
         tailscale_installed,
         tailscale_connected,
         git_installed,
+        git_version_ok,
         python_installed,
+        python_version_ok,
         docker_version,
         tailscale_version,
         git_version,
         python_version,
+        docker_min_version: version_tuple_str(DOCKER_MIN_VERSION),
+        git_min_version: version_tuple_str(GIT_MIN_VERSION),
+        python_min_version: version_tuple_str(PYTHON_MIN_VERSION),
     })
 }
 
-/// Get OS type for platform-specific instructions
+/// Normalize the compiled target architecture into the buckets the frontend
+/// cares about (so it can tell Apple Silicon from Intel, warn on unsupported
+/// architectures, etc), honoring `MOCK_ARCH` in mock mode.
+fn detect_arch() -> String {
+    if is_mock_mode() {
+        if let Ok(mock_arch) = env::var("MOCK_ARCH") {
+            return mock_arch;
+        }
+    }
+
+    match std::env::consts::ARCH {
+        "x86_64" => "x86_64".to_string(),
+        "aarch64" => "aarch64".to_string(),
+        "arm" => "armv7l".to_string(),
+        other => other.to_string(),
+    }
+}
+
+/// Best-effort human-readable OS version: `sw_vers -productVersion` on
+/// macOS, the `cmd ver` build string on Windows, `/etc/os-release`'s
+/// `PRETTY_NAME` on Linux.
+fn detect_os_version(runner: &dyn CommandRunner) -> Option<String> {
+    #[cfg(target_os = "macos")]
+    {
+        return match runner.run(&["sw_vers", "-productVersion"]) {
+            Ok((status, stdout, _)) if status.success() => Some(stdout.trim().to_string()),
+            _ => None,
+        };
+    }
+
+    #[cfg(target_os = "windows")]
+    {
+        // Output looks like "Microsoft Windows [Version 10.0.19045.3086]".
+        return match runner.run(&["cmd", "/C", "ver"]) {
+            Ok((status, stdout, _)) if status.success() => stdout
+                .trim()
+                .rsplit_once("Version ")
+                .map(|(_, rest)| rest.trim_end_matches(']').to_string()),
+            _ => None,
+        };
+    }
+
+    #[cfg(target_os = "linux")]
+    {
+        let _ = runner;
+        return linux_os_release_summary();
+    }
+
+    #[cfg(not(any(target_os = "macos", target_os = "windows", target_os = "linux")))]
+    {
+        let _ = runner;
+        return None;
+    }
+}
+
+#[cfg(target_os = "linux")]
+fn linux_os_release_summary() -> Option<String> {
+    let os_release = std::fs::read_to_string("/etc/os-release").ok()?;
+    for line in os_release.lines() {
+        if let Some(value) = line.strip_prefix("PRETTY_NAME=") {
+            return Some(value.trim_matches('"').to_string());
+        }
+    }
+    None
+}
+
+/// Get platform info (OS family, CPU architecture, OS version) for
+/// platform-specific instructions, e.g. picking a Homebrew prefix or
+/// Docker Desktop build.
 #[tauri::command]
-pub fn get_os_type() -> Result<String, String> {
+pub fn get_os_type() -> Result<PlatformInfo, String> {
     // Mock mode for testing
     if is_mock_mode() {
         if let Ok(mock_platform) = env::var("MOCK_PLATFORM") {
-            return Ok(mock_platform);
+            return Ok(PlatformInfo {
+                os: mock_platform,
+                arch: detect_arch(),
+                os_version: None,
+            });
         }
     }
 
     #[cfg(target_os = "macos")]
-    return Ok("macos".to_string());
+    let os = "macos".to_string();
 
     #[cfg(target_os = "windows")]
-    return Ok("windows".to_string());
+    let os = "windows".to_string();
 
     #[cfg(target_os = "linux")]
-    return Ok("linux".to_string());
+    let os = "linux".to_string();
 
     #[cfg(not(any(target_os = "macos", target_os = "windows", target_os = "linux")))]
-    return Ok("unknown".to_string());
+    let os = "unknown".to_string();
+
+    let runner = ShellCommandRunner;
+    Ok(PlatformInfo {
+        os,
+        arch: detect_arch(),
+        os_version: detect_os_version(&runner),
+    })
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use super::super::command_runner::{env_test_lock, MockCommandRunner, MockResponse};
+
+    #[test]
+    fn test_parse_version_docker() {
+        assert_eq!(parse_version("Docker version 24.0.0, build abc1234"), Some((24, 0, 0)));
+    }
+
+    #[test]
+    fn test_parse_version_git() {
+        assert_eq!(parse_version("git version 2.40.0"), Some((2, 40, 0)));
+    }
+
+    #[test]
+    fn test_parse_version_python_missing_patch() {
+        assert_eq!(parse_version("Python 3.9"), Some((3, 9, 0)));
+    }
+
+    #[test]
+    fn test_parse_version_tailscale_bare() {
+        assert_eq!(parse_version("1.56.0"), Some((1, 56, 0)));
+    }
+
+    #[test]
+    fn test_parse_version_rc_suffix_stops_at_non_digit() {
+        assert_eq!(parse_version("Docker version 24.0.0-rc1"), Some((24, 0, 0)));
+    }
+
+    #[test]
+    fn test_parse_version_mocked_suffix() {
+        assert_eq!(parse_version("Docker version 24.0.0 (MOCKED)"), Some((24, 0, 0)));
+    }
+
+    #[test]
+    fn test_parse_version_missing() {
+        assert_eq!(parse_version("not a version string"), None);
+    }
+
+    #[test]
+    fn test_meets_minimum_pass_and_fail() {
+        assert!(meets_minimum(Some("Docker version 24.0.0"), DOCKER_MIN_VERSION));
+        assert!(!meets_minimum(Some("Docker version 20.10.0"), DOCKER_MIN_VERSION));
+        assert!(!meets_minimum(None, DOCKER_MIN_VERSION));
+    }
 
     #[test]
     fn test_check_docker_returns_tuple() {
-        let (installed, running, version) = check_docker();
+        let runner = ShellCommandRunner;
+        let (installed, running, version) = check_docker(&runner);
         // Just verify it returns without panicking
         // Actual values depend on system state
         if installed {
@@ -248,7 +554,8 @@ mod tests {
 
     #[test]
     fn test_check_tailscale_returns_tuple() {
-        let (installed, connected, version) = check_tailscale();
+        let runner = ShellCommandRunner;
+        let (installed, connected, version) = check_tailscale(&runner);
         if installed {
             assert!(version.is_some());
         }
@@ -264,4 +571,184 @@ mod tests {
             status.docker_installed, status.docker_running,
             status.tailscale_installed, status.tailscale_connected);
     }
+
+    #[test]
+    fn test_check_docker_daemon_down() {
+        let runner = MockCommandRunner::new()
+            .with(&["docker", "--version"], MockResponse::ok("Docker version 24.0.0, build abc1234"))
+            .with(&["docker", "info"], MockResponse::fail("Cannot connect to the Docker daemon"));
+
+        let (installed, running, version) = check_docker(&runner);
+        assert!(installed);
+        assert!(!running);
+        assert_eq!(version.as_deref(), Some("Docker version 24.0.0, build abc1234"));
+    }
+
+    #[test]
+    fn test_check_docker_old_version() {
+        let runner = MockCommandRunner::new()
+            .with(&["docker", "--version"], MockResponse::ok("Docker version 20.10.0, build abc1234"))
+            .with(&["docker", "info"], MockResponse::ok(""));
+
+        let (installed, running, version) = check_docker(&runner);
+        assert!(installed);
+        assert!(running);
+        assert!(!meets_minimum(version.as_deref(), DOCKER_MIN_VERSION));
+    }
+
+    #[test]
+    fn test_check_docker_not_found() {
+        let runner = MockCommandRunner::new();
+        let (installed, running, version) = check_docker(&runner);
+        assert!(!installed);
+        assert!(!running);
+        assert!(version.is_none());
+    }
+
+    #[test]
+    fn test_check_python_python2_only() {
+        let runner = MockCommandRunner::new()
+            .with(&["python3", "--version"], MockResponse::fail("command not found"))
+            .with(&["python", "--version"], MockResponse::ok("Python 2.7.18"));
+
+        let (installed, version) = check_python(&runner);
+        assert!(!installed);
+        assert!(version.is_none());
+    }
+
+    #[test]
+    fn test_check_python3_installed() {
+        let runner = MockCommandRunner::new()
+            .with(&["python3", "--version"], MockResponse::ok("Python 3.11.0"));
+
+        let (installed, version) = check_python(&runner);
+        assert!(installed);
+        assert_eq!(version.as_deref(), Some("Python 3.11.0"));
+    }
+
+    #[test]
+    fn test_check_tailscale_installed_not_connected() {
+        let runner = MockCommandRunner::new()
+            .with(&["tailscale", "--version"], MockResponse::ok("1.56.0"))
+            .with(&["tailscale", "status"], MockResponse::fail("Tailscale is stopped"));
+
+        let (installed, connected, version) = check_tailscale(&runner);
+        assert!(installed);
+        assert!(!connected);
+        assert_eq!(version.as_deref(), Some("1.56.0"));
+    }
+
+    #[test]
+    fn test_check_git_not_found() {
+        let runner = MockCommandRunner::new();
+        let (installed, version) = check_git(&runner);
+        assert!(!installed);
+        assert!(version.is_none());
+    }
+
+    #[test]
+    fn test_check_docker_custom_binary_override() {
+        let _guard = env_test_lock();
+        let runner = MockCommandRunner::new()
+            .with(&["docker-custom", "--version"], MockResponse::ok("Docker version 25.0.0"))
+            .with(&["docker-custom", "info"], MockResponse::ok(""));
+
+        env::set_var("USHADOW_DOCKER_BINARY", "docker-custom");
+        let (installed, running, version) = check_docker(&runner);
+        env::remove_var("USHADOW_DOCKER_BINARY");
+
+        assert!(installed);
+        assert!(running);
+        assert_eq!(version.as_deref(), Some("Docker version 25.0.0"));
+    }
+
+    #[test]
+    fn test_check_python_custom_binary_requires_python3() {
+        let _guard = env_test_lock();
+        let runner = MockCommandRunner::new()
+            .with(&["python3.12", "--version"], MockResponse::ok("Python 2.7.18"));
+
+        env::set_var("USHADOW_PYTHON_BINARY", "python3.12");
+        let (installed, version) = check_python(&runner);
+        env::remove_var("USHADOW_PYTHON_BINARY");
+
+        assert!(!installed);
+        assert!(version.is_none());
+    }
+
+    #[test]
+    fn test_check_python_custom_binary_accepted() {
+        let _guard = env_test_lock();
+        let runner = MockCommandRunner::new()
+            .with(&["python3.12", "--version"], MockResponse::ok("Python 3.12.1"));
+
+        env::set_var("USHADOW_PYTHON_BINARY", "python3.12");
+        let (installed, version) = check_python(&runner);
+        env::remove_var("USHADOW_PYTHON_BINARY");
+
+        assert!(installed);
+        assert_eq!(version.as_deref(), Some("Python 3.12.1"));
+    }
+
+    #[test]
+    fn test_extra_search_dirs_parses_colon_separated_list() {
+        let _guard = env_test_lock();
+        env::set_var("USHADOW_TEST_EXTRA_PATHS", "/a/bin:/b/bin");
+        assert_eq!(extra_search_dirs("USHADOW_TEST_EXTRA_PATHS"), vec!["/a/bin", "/b/bin"]);
+        env::remove_var("USHADOW_TEST_EXTRA_PATHS");
+    }
+
+    #[test]
+    fn test_detect_arch_mock_override() {
+        let _guard = env_test_lock();
+        env::set_var("MOCK_MODE", "true");
+        env::set_var("MOCK_ARCH", "armv7l");
+        assert_eq!(detect_arch(), "armv7l");
+        env::remove_var("MOCK_ARCH");
+        env::remove_var("MOCK_MODE");
+    }
+
+    #[test]
+    fn test_run_tool_checks_builds_status_for_every_registered_tool() {
+        let runner: Arc<dyn CommandRunner> = Arc::new(
+            MockCommandRunner::new()
+                .with(&["docker", "--version"], MockResponse::ok("Docker version 24.0.0"))
+                .with(&["docker", "info"], MockResponse::ok(""))
+                .with(&["git", "--version"], MockResponse::ok("git version 2.40.0")),
+        );
+
+        let statuses = run_tool_checks(runner);
+        let names: Vec<&str> = statuses.iter().map(|s| s.name.as_str()).collect();
+        assert_eq!(names, vec!["docker", "git", "tailscale", "python"]);
+
+        let docker = statuses.iter().find(|s| s.name == "docker").unwrap();
+        assert!(docker.installed);
+        assert!(docker.version_ok);
+        assert_eq!(docker.connected, Some(true));
+
+        let tailscale = statuses.iter().find(|s| s.name == "tailscale").unwrap();
+        assert!(!tailscale.installed);
+        assert_eq!(tailscale.connected, Some(false));
+    }
+
+    #[test]
+    fn test_check_tools_returns_status_list() {
+        let result = check_tools();
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap().len(), tool_registry().len());
+    }
+
+    #[test]
+    fn test_get_os_type_mock_platform() {
+        let _guard = env_test_lock();
+        env::set_var("MOCK_MODE", "true");
+        env::set_var("MOCK_PLATFORM", "linux");
+        env::set_var("MOCK_ARCH", "x86_64");
+        let info = get_os_type().unwrap();
+        assert_eq!(info.os, "linux");
+        assert_eq!(info.arch, "x86_64");
+        env::remove_var("MOCK_ARCH");
+        env::remove_var("MOCK_PLATFORM");
+        env::remove_var("MOCK_MODE");
+    }
 }
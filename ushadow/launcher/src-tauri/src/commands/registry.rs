@@ -0,0 +1,148 @@
+use std::fs;
+use std::path::PathBuf;
+use serde::{Deserialize, Serialize};
+
+/// How a container name is matched to a `ServiceDefinition`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum MatchRule {
+    /// Container name equals `value` exactly.
+    Exact { value: String },
+    /// Container name is `value` or ends with `-value` (also matches compose's `-1` suffix).
+    Suffix { value: String },
+    /// Container carries a Docker label `key=value` (e.g. `com.ushadow.role=backend`).
+    Label { key: String, value: String },
+}
+
+/// How a backend's webui port is derived from its own port, when applicable.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum WebuiPortRule {
+    /// This service has no companion webui port.
+    None,
+    /// `webui_port = backend_port - offset`.
+    Offset { offset: u16 },
+}
+
+/// A single entry in the user-configurable service registry.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ServiceDefinition {
+    pub name: String,
+    pub display_name: String,
+    pub match_rule: MatchRule,
+    /// True for Ushadow backend services (grouped into `UshadowEnvironment`s);
+    /// false for plain infrastructure services (grouped into `InfraService`s).
+    pub is_backend: bool,
+    #[serde(default)]
+    pub webui_port_rule: Option<WebuiPortRule>,
+}
+
+/// The full set of services discovery should recognize, replacing the
+/// previously hardcoded `INFRA_PATTERNS` and backend-naming heuristics.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ServiceRegistry {
+    pub services: Vec<ServiceDefinition>,
+}
+
+impl ServiceRegistry {
+    /// The registry shipped out of the box, matching the previous hardcoded behavior.
+    pub fn default_registry() -> Self {
+        let infra = [("mongo", "MongoDB"), ("redis", "Redis"), ("neo4j", "Neo4j"), ("qdrant", "Qdrant")];
+
+        let mut services: Vec<ServiceDefinition> = infra
+            .iter()
+            .map(|(name, display_name)| ServiceDefinition {
+                name: name.to_string(),
+                display_name: display_name.to_string(),
+                match_rule: MatchRule::Suffix { value: name.to_string() },
+                is_backend: false,
+                webui_port_rule: None,
+            })
+            .collect();
+
+        services.push(ServiceDefinition {
+            name: "ushadow-backend".to_string(),
+            display_name: "Ushadow Backend".to_string(),
+            match_rule: MatchRule::Suffix { value: "backend".to_string() },
+            is_backend: true,
+            webui_port_rule: Some(WebuiPortRule::Offset { offset: 5000 }),
+        });
+
+        Self { services }
+    }
+}
+
+/// Path to the registry file in the platform config directory, e.g.
+/// `~/.config/ushadow-launcher/service_registry.json` on Linux.
+fn config_path() -> Result<PathBuf, String> {
+    let base = config_dir()?;
+    Ok(base.join("ushadow-launcher").join("service_registry.json"))
+}
+
+fn config_dir() -> Result<PathBuf, String> {
+    #[cfg(target_os = "windows")]
+    {
+        std::env::var("APPDATA")
+            .map(PathBuf::from)
+            .map_err(|_| "APPDATA is not set".to_string())
+    }
+
+    #[cfg(target_os = "macos")]
+    {
+        std::env::var("HOME")
+            .map(|home| PathBuf::from(home).join("Library/Application Support"))
+            .map_err(|_| "HOME is not set".to_string())
+    }
+
+    #[cfg(target_os = "linux")]
+    {
+        if let Ok(xdg) = std::env::var("XDG_CONFIG_HOME") {
+            return Ok(PathBuf::from(xdg));
+        }
+        std::env::var("HOME")
+            .map(|home| PathBuf::from(home).join(".config"))
+            .map_err(|_| "HOME is not set".to_string())
+    }
+
+    #[cfg(not(any(target_os = "windows", target_os = "macos", target_os = "linux")))]
+    {
+        Ok(PathBuf::from("."))
+    }
+}
+
+/// Load the service registry from disk, falling back to (and persisting) the
+/// default registry if none has been configured yet. Re-reads the file on
+/// every call so edits made outside the app take effect without a restart.
+#[tauri::command]
+pub fn get_service_registry() -> Result<ServiceRegistry, String> {
+    let path = config_path()?;
+
+    if !path.exists() {
+        let default = ServiceRegistry::default_registry();
+        set_service_registry(default.clone())?;
+        return Ok(default);
+    }
+
+    let contents = fs::read_to_string(&path)
+        .map_err(|e| format!("Failed to read service registry: {}", e))?;
+
+    serde_json::from_str(&contents)
+        .map_err(|e| format!("Failed to parse service registry: {}", e))
+}
+
+/// Persist a new service registry, creating the config directory if needed.
+#[tauri::command]
+pub fn set_service_registry(registry: ServiceRegistry) -> Result<(), String> {
+    let path = config_path()?;
+
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)
+            .map_err(|e| format!("Failed to create config directory: {}", e))?;
+    }
+
+    let contents = serde_json::to_string_pretty(&registry)
+        .map_err(|e| format!("Failed to serialize service registry: {}", e))?;
+
+    fs::write(&path, contents)
+        .map_err(|e| format!("Failed to write service registry: {}", e))
+}
@@ -0,0 +1,71 @@
+use serde::Serialize;
+use tauri::{AppHandle, Manager};
+
+/// Summary of an available update, surfaced to the "Check for Updates" tray item.
+#[derive(Debug, Clone, Serialize)]
+pub struct UpdateInfo {
+    pub version: String,
+    pub notes: Option<String>,
+    pub pub_date: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct UpdaterProgressPayload {
+    stage: String,
+}
+
+fn emit_progress(app: &AppHandle, stage: &str) {
+    let _ = app.emit_all("updater://progress", UpdaterProgressPayload { stage: stage.to_string() });
+}
+
+/// Query the release manifest and compare it against the running version.
+/// Returns `None` when already up to date.
+#[tauri::command]
+pub async fn check_for_update(app: AppHandle) -> Result<Option<UpdateInfo>, String> {
+    emit_progress(&app, "checking");
+
+    let update = app
+        .updater()
+        .check()
+        .await
+        .map_err(|e| format!("Failed to check for updates: {}", e))?;
+
+    if !update.is_update_available() {
+        emit_progress(&app, "up-to-date");
+        return Ok(None);
+    }
+
+    Ok(Some(UpdateInfo {
+        version: update.latest_version().to_string(),
+        notes: update.body().map(|s| s.to_string()),
+        pub_date: update.date().map(|d| d.to_string()),
+    }))
+}
+
+/// Download and apply the available update, then restart the launcher right
+/// away; the user is not asked before the restart.
+/// Errors if no update is available (call `check_for_update` first).
+#[tauri::command]
+pub async fn install_update(app: AppHandle) -> Result<(), String> {
+    let update = app
+        .updater()
+        .check()
+        .await
+        .map_err(|e| format!("Failed to check for updates: {}", e))?;
+
+    if !update.is_update_available() {
+        return Err("No update available".to_string());
+    }
+
+    emit_progress(&app, "downloading");
+
+    update
+        .download_and_install()
+        .await
+        .map_err(|e| format!("Failed to install update: {}", e))?;
+
+    emit_progress(&app, "installed");
+
+    app.restart();
+    Ok(())
+}
@@ -0,0 +1,174 @@
+use std::io::{BufRead, BufReader};
+use std::process::Stdio;
+use std::time::Duration;
+use serde::Deserialize;
+use tauri::{AppHandle, Manager, State};
+use tokio_util::sync::CancellationToken;
+use super::discovery::{discover_environments, matches_any_service};
+use super::docker::AppState;
+use super::registry::{get_service_registry, ServiceRegistry};
+use super::utils::silent_command;
+
+/// Events we care about from `docker events`; anything else is ignored.
+const WATCHED_ACTIONS: &[&str] = &["start", "stop", "die", "health_status"];
+
+/// Minimal shape of a `docker events --format '{{json .}}'` line.
+#[derive(Debug, Deserialize)]
+struct DockerEvent {
+    #[serde(rename = "Action")]
+    action: String,
+    #[serde(rename = "Actor")]
+    actor: DockerEventActor,
+}
+
+#[derive(Debug, Deserialize)]
+struct DockerEventActor {
+    #[serde(rename = "Attributes", default)]
+    attributes: std::collections::HashMap<String, String>,
+}
+
+impl DockerEvent {
+    fn container_name(&self) -> Option<&str> {
+        self.actor.attributes.get("name").map(|s| s.as_str())
+    }
+
+    /// An event is interesting if its action matches one of our watched
+    /// actions and the container matches a service in the registry (infra or backend).
+    fn is_relevant(&self, registry: &ServiceRegistry) -> bool {
+        let action = self.action.split(':').next().unwrap_or(&self.action);
+        if !WATCHED_ACTIONS.contains(&action) {
+            return false;
+        }
+        match self.container_name() {
+            Some(name) => matches_any_service(name, &self.actor.attributes, registry),
+            None => false,
+        }
+    }
+}
+
+/// Start a background watcher that turns `docker events` into debounced
+/// `environments-changed` events carrying a fresh `DiscoveryResult`.
+#[tauri::command]
+pub async fn start_environment_watcher(app: AppHandle, state: State<'_, AppState>) -> Result<(), String> {
+    let mut guard = state.environment_watcher.lock().map_err(|e| e.to_string())?;
+    if guard.is_some() {
+        return Ok(()); // already running
+    }
+
+    let token = CancellationToken::new();
+    *guard = Some(token.clone());
+    drop(guard);
+
+    tokio::spawn(watch_loop(app, token));
+    Ok(())
+}
+
+/// Cancel the watcher loop immediately, same as `stop_monitoring`/`stop_log_stream`.
+#[tauri::command]
+pub async fn stop_environment_watcher(state: State<'_, AppState>) -> Result<(), String> {
+    let mut guard = state.environment_watcher.lock().map_err(|e| e.to_string())?;
+    if let Some(token) = guard.take() {
+        token.cancel();
+    }
+    Ok(())
+}
+
+async fn watch_loop(app: AppHandle, token: CancellationToken) {
+    let mut backoff = Duration::from_secs(1);
+    const MAX_BACKOFF: Duration = Duration::from_secs(30);
+
+    while !token.is_cancelled() {
+        match run_docker_events(&app, &token).await {
+            Ok(()) => backoff = Duration::from_secs(1), // only returned once `token` is cancelled
+            Err(_) => {
+                tokio::select! {
+                    _ = token.cancelled() => return,
+                    _ = tokio::time::sleep(backoff) => {}
+                }
+                backoff = std::cmp::min(backoff * 2, MAX_BACKOFF);
+            }
+        }
+    }
+}
+
+/// Run a single `docker events` subprocess until it exits or `token` is
+/// cancelled, emitting a debounced `environments-changed` event for each
+/// relevant burst.
+///
+/// `docker events` line reads are blocking, so (mirroring `monitor.rs`'s
+/// `log_stream_loop`) they happen on a dedicated worker thread and are
+/// forwarded over a channel the async loop can `select!` on alongside
+/// cancellation — otherwise cancellation would only be checked between
+/// lines, and `docker events` can go arbitrarily long between lines.
+async fn run_docker_events(app: &AppHandle, token: &CancellationToken) -> Result<(), String> {
+    let mut child = silent_command("docker")
+        .args(["events", "--format", "{{json .}}"])
+        .stdout(Stdio::piped())
+        .spawn()
+        .map_err(|e| format!("Failed to start docker events: {}", e))?;
+
+    let stdout = child.stdout.take().ok_or("Failed to capture docker events stdout")?;
+    let reader = BufReader::new(stdout);
+    let registry = get_service_registry().unwrap_or_else(|_| ServiceRegistry::default_registry());
+
+    let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+    std::thread::spawn(move || {
+        for line in reader.lines() {
+            match line {
+                Ok(l) => {
+                    if tx.send(l).is_err() {
+                        break;
+                    }
+                }
+                Err(_) => break,
+            }
+        }
+    });
+
+    let mut pending = false;
+    let mut last_emit = tokio::time::Instant::now() - Duration::from_secs(1);
+    const DEBOUNCE: Duration = Duration::from_millis(200);
+
+    loop {
+        let line = tokio::select! {
+            _ = token.cancelled() => {
+                let _ = child.kill();
+                return Ok(());
+            }
+            line = rx.recv() => line,
+        };
+
+        let Some(line) = line else { break }; // worker thread exited, e.g. docker events exited
+
+        let event: DockerEvent = match serde_json::from_str(&line) {
+            Ok(e) => e,
+            Err(_) => continue,
+        };
+
+        if !event.is_relevant(&registry) {
+            continue;
+        }
+
+        pending = true;
+
+        // Coalesce bursts: only refresh+emit once enough time has passed
+        // since the last emit, so a `compose up` of many containers
+        // doesn't spam the frontend with one event per container.
+        if last_emit.elapsed() >= DEBOUNCE {
+            if let Ok(result) = discover_environments().await {
+                let _ = app.emit_all("environments-changed", &result);
+            }
+            last_emit = tokio::time::Instant::now();
+            pending = false;
+        }
+    }
+
+    if pending {
+        if let Ok(result) = discover_environments().await {
+            let _ = app.emit_all("environments-changed", &result);
+        }
+    }
+
+    let _ = child.wait();
+    Err("docker events stream ended".to_string())
+}
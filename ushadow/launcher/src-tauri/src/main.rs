@@ -6,14 +6,37 @@
 mod commands;
 mod models;
 
-use commands::{AppState, check_prerequisites, get_os_type, discover_environments,
+use commands::{AppState, check_prerequisites, check_tools, get_os_type, discover_environments, discover_environments_multi,
     start_containers, stop_containers, get_container_status,
     check_backend_health, check_webui_health, open_browser, set_project_root,
-    create_environment, install_docker_via_brew, start_docker_desktop_macos,
+    create_environment, cancel_environment_creation, install_docker_via_brew, list_brew_variants, start_docker_desktop_macos,
     start_docker_desktop_windows, start_docker_service_linux,
+    install_docker_linux, install_git_linux, install_tailscale_linux,
     // Project/repo management
     get_default_project_dir, check_project_dir, clone_ushadow_repo,
-    update_ushadow_repo, install_git_windows, install_git_macos};
+    update_ushadow_repo, install_git_windows, install_git_macos, upgrade_all,
+    // Service lifecycle
+    start_service, stop_service, restart_service, wait_for_ready,
+    // Live discovery watcher
+    start_environment_watcher, stop_environment_watcher,
+    // Service registry
+    get_service_registry, set_service_registry,
+    // Container/log event streaming
+    StreamState, start_monitoring, stop_monitoring, stream_logs, stop_log_stream,
+    // Start-at-login
+    get_autolaunch_enabled, set_autolaunch,
+    // Compose file watcher
+    ProjectWatcherState, start_watching_project, stop_watching_project,
+    // Self-update
+    check_for_update, install_update,
+    // Health monitoring
+    start_health_monitor, stop_health_monitor,
+    // Linux install commands
+    get_linux_install_plan,
+    // Preflight checks
+    run_preflight,
+    // Idempotent tool install
+    ensure_tool, invalidate_tool_cache};
 use tauri::{
     CustomMenuItem, Manager, Menu, MenuItem, SystemTray,
     SystemTrayEvent, SystemTrayMenu, SystemTrayMenuItem, Submenu,
@@ -22,11 +45,18 @@ use tauri::{
 /// Create system tray menu
 fn create_tray_menu() -> SystemTrayMenu {
     let open = CustomMenuItem::new("open".to_string(), "Open Launcher");
+    let autolaunch_enabled = get_autolaunch_enabled().unwrap_or(false);
+    let autolaunch = CustomMenuItem::new("toggle_autolaunch".to_string(), "Launch at Login")
+        .selected(autolaunch_enabled);
+    let check_updates = CustomMenuItem::new("check_for_updates".to_string(), "Check for Updates");
     let quit = CustomMenuItem::new("quit".to_string(), "Quit");
 
     SystemTrayMenu::new()
         .add_item(open)
         .add_native_item(SystemTrayMenuItem::Separator)
+        .add_item(autolaunch)
+        .add_item(check_updates)
+        .add_native_item(SystemTrayMenuItem::Separator)
         .add_item(quit)
 }
 
@@ -51,6 +81,8 @@ fn main() {
 
     tauri::Builder::default()
         .manage(AppState::new())
+        .manage(StreamState::new())
+        .manage(ProjectWatcherState::new())
         .menu(menu)
         .on_menu_event(|event| {
             let window = event.window();
@@ -77,6 +109,29 @@ fn main() {
                         let _ = window.set_focus();
                     }
                 }
+                "toggle_autolaunch" => {
+                    let currently_enabled = get_autolaunch_enabled().unwrap_or(false);
+                    if set_autolaunch(!currently_enabled).is_ok() {
+                        let item = app.tray_handle().get_item(&id);
+                        let _ = item.set_selected(!currently_enabled);
+                    }
+                }
+                "check_for_updates" => {
+                    let app_handle = app.clone();
+                    tauri::async_runtime::spawn(async move {
+                        match check_for_update(app_handle.clone()).await {
+                            Ok(Some(info)) => {
+                                if let Some(window) = app_handle.get_window("main") {
+                                    let _ = window.show();
+                                    let _ = window.set_focus();
+                                }
+                                println!("Update available: {}", info.version);
+                            }
+                            Ok(None) => println!("Ushadow Launcher is up to date"),
+                            Err(e) => eprintln!("Update check failed: {}", e),
+                        }
+                    });
+                }
                 "quit" => {
                     std::process::exit(0);
                 }
@@ -84,12 +139,25 @@ fn main() {
             },
             _ => {}
         })
-        .on_window_event(|_event| {
+        .on_window_event(|event| {
             // Allow window to close normally (quit app)
             // Previously hid window and kept in tray, but that's disabled for now
+            if let tauri::WindowEvent::CloseRequested { .. } = event.event() {
+                let stream_state = event.window().state::<StreamState>();
+                if let Ok(mut monitors) = stream_state.status_monitors.lock() {
+                    monitors.drain().for_each(|(_, token)| token.cancel());
+                }
+                if let Ok(mut streams) = stream_state.log_streams.lock() {
+                    streams.drain().for_each(|(_, token)| token.cancel());
+                }
+                if let Ok(mut health_monitors) = stream_state.health_monitors.lock() {
+                    health_monitors.drain().for_each(|(_, token)| token.cancel());
+                }
+            }
         })
         .invoke_handler(tauri::generate_handler![
             check_prerequisites,
+            check_tools,
             get_os_type,
             set_project_root,
             start_containers,
@@ -99,11 +167,17 @@ fn main() {
             check_webui_health,
             open_browser,
             discover_environments,
+            discover_environments_multi,
             create_environment,
+            cancel_environment_creation,
             install_docker_via_brew,
+            list_brew_variants,
             start_docker_desktop_macos,
             start_docker_desktop_windows,
             start_docker_service_linux,
+            install_docker_linux,
+            install_git_linux,
+            install_tailscale_linux,
             // Project/repo management
             get_default_project_dir,
             check_project_dir,
@@ -111,6 +185,42 @@ fn main() {
             update_ushadow_repo,
             install_git_windows,
             install_git_macos,
+            upgrade_all,
+            // Service lifecycle
+            start_service,
+            stop_service,
+            restart_service,
+            wait_for_ready,
+            // Live discovery watcher
+            start_environment_watcher,
+            stop_environment_watcher,
+            // Service registry
+            get_service_registry,
+            set_service_registry,
+            // Container/log event streaming
+            start_monitoring,
+            stop_monitoring,
+            stream_logs,
+            stop_log_stream,
+            // Start-at-login
+            get_autolaunch_enabled,
+            set_autolaunch,
+            // Compose file watcher
+            start_watching_project,
+            stop_watching_project,
+            // Self-update
+            check_for_update,
+            install_update,
+            // Health monitoring
+            start_health_monitor,
+            stop_health_monitor,
+            // Linux install commands
+            get_linux_install_plan,
+            // Preflight checks
+            run_preflight,
+            // Idempotent tool install
+            ensure_tool,
+            invalidate_tool_cache,
         ])
         .setup(|app| {
             let window = app.get_window("main").unwrap();
@@ -5,12 +5,32 @@ use serde::{Deserialize, Serialize};
 pub struct PrerequisiteStatus {
     pub docker_installed: bool,
     pub docker_running: bool,
+    pub docker_version_ok: bool,
     pub tailscale_installed: bool,
     pub tailscale_connected: bool,
     pub git_installed: bool,
+    pub git_version_ok: bool,
+    pub python_installed: bool,
+    pub python_version_ok: bool,
     pub docker_version: Option<String>,
     pub tailscale_version: Option<String>,
     pub git_version: Option<String>,
+    pub python_version: Option<String>,
+    /// The minimum versions enforced above, surfaced so the UI can explain
+    /// *why* a version was flagged as too old (e.g. "Docker >= 24.0.0").
+    pub docker_min_version: String,
+    pub git_min_version: String,
+    pub python_min_version: String,
+}
+
+/// Machine identification, richer than a bare OS name so installers can pick
+/// the right package/binary for this machine (Homebrew prefix on Apple
+/// Silicon vs Intel, Docker Desktop build, Tailscale package, etc).
+#[derive(Serialize, Deserialize, Clone)]
+pub struct PlatformInfo {
+    pub os: String,
+    pub arch: String,
+    pub os_version: Option<String>,
 }
 
 /// Project location status
@@ -48,6 +68,56 @@ pub struct UshadowEnvironment {
     pub webui_port: Option<u16>,
     pub running: bool,
     pub tailscale_active: bool,
+    /// Label of the Docker host this environment was discovered on, e.g. "local"
+    /// or a remote context's name. See `DockerContext` in the discovery module.
+    pub host: String,
+}
+
+/// Status of one tool from the prerequisite registry (see `ToolCheck` in
+/// `commands::prerequisites`), generalizing the old per-tool booleans into a
+/// list so the frontend can render any number of tools uniformly.
+#[derive(Serialize, Deserialize, Clone)]
+pub struct ToolStatus {
+    pub name: String,
+    pub installed: bool,
+    pub version: Option<String>,
+    pub version_ok: bool,
+    pub min_version: String,
+    /// Daemon/VPN connectivity state for tools that have one (Docker daemon
+    /// running, Tailscale connected). `None` for tools with no such concept,
+    /// e.g. Git or Python.
+    pub connected: Option<bool>,
+}
+
+/// Per-tool Linux install commands for whatever prerequisites are missing,
+/// built from the detected package manager and distro.
+#[derive(Serialize, Deserialize, Clone)]
+pub struct LinuxInstallPlan {
+    pub package_manager: Option<String>,
+    pub distro: Option<String>,
+    pub commands: std::collections::HashMap<String, String>,
+    /// Set when the package manager or distro couldn't be confidently
+    /// detected, so the UI can warn the user the commands are best-effort.
+    pub warning: Option<String>,
+}
+
+/// Outcome of one step in `upgrade_all`'s maintenance run.
+#[derive(Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum UpgradeStepStatus {
+    Success,
+    Failed,
+    /// The step doesn't apply to this machine, e.g. no package manager detected.
+    Skipped,
+}
+
+/// Result of one `upgrade_all` step. Collected independently per step
+/// (topgrade's model) so one failure doesn't stop the rest from running.
+#[derive(Serialize, Deserialize, Clone)]
+pub struct UpgradeStepResult {
+    pub step_name: String,
+    pub status: UpgradeStepStatus,
+    pub output: String,
 }
 
 /// Infrastructure service status
@@ -59,6 +129,19 @@ pub struct InfraService {
     pub ports: Option<String>,
 }
 
+/// Result of probing an HTTP endpoint with retry/backoff, replacing a bare
+/// healthy/unhealthy bool. A response with an unhealthy status has
+/// `http_status` set and `reachable` false; no response at all leaves
+/// `http_status` as `None`. A closed port and a timeout both end up as
+/// "no response" and are not told apart.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct HealthReport {
+    pub reachable: bool,
+    pub http_status: Option<u16>,
+    pub latency_ms: u64,
+    pub attempts: u32,
+}
+
 /// Environment discovery result
 #[derive(Serialize, Deserialize, Clone)]
 pub struct DiscoveryResult {